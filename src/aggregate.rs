@@ -0,0 +1,196 @@
+use std::{
+	fmt::Debug,
+	iter::{
+		self,
+		Sum
+	},
+};
+
+use crate::{
+	node::Node,
+	iter::next_in_subtree,
+};
+
+pub trait AggregateNode<T: Debug + Clone> {
+	fn fold_contents<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B;
+	fn sum_contents(&self) -> T where T: Sum<T>;
+	fn sum_by<S: Sum<S>, F: FnMut(&T) -> S>(&self, f: F) -> S;
+	fn aggregate<A, F: FnMut(&T, &[A]) -> A>(&self, f: F) -> A;
+	fn propagate<C, F: FnMut(&mut T, &C) -> C>(&self, root_ctx: C, f: F);
+}
+
+impl<T: Debug + Clone> AggregateNode<T> for Node<T> {
+	/// Folds the contents of `&self` and all its descendants, in document order.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(3)), node!(4));
+	///
+	///		let visited = node.fold_contents(String::new(), |acc, n| format!("{}{}", acc, n));
+	///		assert_eq!(visited, "1234");
+	/// }
+	/// ```
+	fn fold_contents<B, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> B {
+		let mut acc = init;
+		let mut next = Some(self.clone());
+
+		while let Some(node) = next {
+			acc = f(acc, &node.get().content);
+			next = next_in_subtree(&node, self);
+		}
+
+		acc
+	}
+
+	/// Sums the contents of `&self` and all its descendants.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// #[derive(Debug, Clone)]
+	/// struct File { name: &'static str, size: u64 }
+	///
+	/// fn main() {
+	///		let budget = node!(100, node!(20, node!(5)), node!(30));
+	///		assert_eq!(budget.sum_contents(), 155);
+	///
+	///		let dir = node!(File { name: "src", size: 0 },
+	///			node!(File { name: "lib.rs", size: 120 }),
+	///			node!(File { name: "node.rs", size: 800 })
+	///		);
+	///		assert_eq!(dir.sum_by(|f| f.size), 920);
+	/// }
+	/// ```
+	fn sum_contents(&self) -> T where T: Sum<T> {
+		self.sum_by(|content| content.clone())
+	}
+
+	/// Sums a projection of the contents of `&self` and all its descendants.
+	fn sum_by<S: Sum<S>, F: FnMut(&T) -> S>(&self, mut f: F) -> S {
+		let mut next = Some(self.clone());
+
+		iter::from_fn(|| {
+			let node = next.take()?;
+			next = next_in_subtree(&node, self);
+
+			let value = f(&node.get().content);
+			Some(value)
+		}).sum()
+	}
+
+	/// Computes a value for `&self` bottom-up: `f` gets the content of every node along with
+	/// the values computed for its children, in order. The subtree is walked in a single
+	/// iterative post-order pass, making this the skeleton for layout sizing,
+	/// subtree statistics and synthesized attributes.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("a", node!("b", node!("c"), node!("d")), node!("e"));
+	///
+	///		let height = node.aggregate(|_, children: &[usize]| 1 + children.iter().max().unwrap_or(&0));
+	///		assert_eq!(height, 3);
+	///
+	///		let shape = node.aggregate(|content, children: &[String]| {
+	///			if children.is_empty() {
+	///				return content.to_string();
+	///			}
+	///			format!("{}[{}]", content, children.join(" "))
+	///		});
+	///		assert_eq!(shape, "a[b[c d] e]");
+	/// }
+	/// ```
+	fn aggregate<A, F: FnMut(&T, &[A]) -> A>(&self, mut f: F) -> A {
+		// the nodes being computed, each one with the values of its children so far.
+		let mut open: Vec<(Node<T>, Vec<A>)> = vec![(self.clone(), Vec::new())];
+		let mut next = self.child();
+
+		loop {
+			if let Some(node) = next {
+				next = node.child();
+				open.push((node, Vec::new()));
+				continue;
+			}
+
+			// every child of the last open node was computed.
+			let (node, children) = open.pop().unwrap();
+			let value = f(&node.get().content, &children);
+
+			match open.last_mut() {
+				Some((_, siblings)) => {
+					siblings.push(value);
+					next = node.next();
+				},
+				None => return value
+			}
+		}
+	}
+
+	/// Runs a top-down pass over `&self` and its descendants: `f` gets the content of every node
+	/// along with the context derived by its parent ( `root_ctx` for `&self` ), and returns
+	/// the context handed to the children of the node. The subtree is walked iteratively in pre-order,
+	/// covering style inheritance and scoping passes without hand-written recursion.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// #[derive(Debug, Clone)]
+	/// struct Element { color: Option<&'static str>, computed: &'static str }
+	///
+	/// fn el(color: Option<&'static str>) -> Element {
+	///		Element { color, computed: "" }
+	/// }
+	///
+	/// fn main() {
+	///		let body = node!(el(None), node!(el(Some("red")), node!(el(None))), node!(el(None)));
+	///
+	///		body.propagate("black", |element, inherited| {
+	///			element.computed = element.color.unwrap_or(inherited);
+	///			element.computed
+	///		});
+	///
+	///		assert_eq!(body.first_leaf().unwrap().get().content.computed, "red");
+	///		assert_eq!(body.get_last_child().unwrap().get().content.computed, "black");
+	/// }
+	/// ```
+	fn propagate<C, F: FnMut(&mut T, &C) -> C>(&self, root_ctx: C, mut f: F) {
+		let ctx = f(&mut self.get_mut().content, &root_ctx);
+
+		// for every depth-level, the next node to visit and the context derived by its parent.
+		let mut levels: Vec<(Option<Node<T>>, C)> = vec![(self.child(), ctx)];
+
+		loop {
+			let (node, ctx) = match levels.last_mut() {
+				Some((next, parent_ctx)) => match next.take() {
+					Some(node) => {
+						*next = node.next();
+						let ctx = f(&mut node.get_mut().content, parent_ctx);
+						(node, ctx)
+					},
+					None => {
+						levels.pop();
+						continue;
+					}
+				},
+				None => return
+			};
+
+			levels.push((node.child(), ctx));
+		}
+	}
+}
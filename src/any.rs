@@ -0,0 +1,229 @@
+use std::{
+	any::Any,
+	fmt::{
+		self,
+		Debug
+	},
+};
+
+use crate::node::Node;
+
+/// Object-safe `Clone + Debug` for the values stored in `AnyContent`.
+trait DynContent: Any + Debug {
+	fn clone_box(&self) -> Box<dyn DynContent>;
+	fn as_any(&self) -> &dyn Any;
+	fn as_any_mut(&mut self) -> &mut dyn Any;
+	fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<U: Any + Debug + Clone> DynContent for U {
+	fn clone_box(&self) -> Box<dyn DynContent> {
+		Box::new(self.clone())
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+
+	fn as_any_mut(&mut self) -> &mut dyn Any {
+		self
+	}
+
+	fn into_any(self: Box<Self>) -> Box<dyn Any> {
+		self
+	}
+}
+
+/// A type-erased content, holding a value of any `'static + Debug + Clone` type.
+/// Lets frameworks build trees mixing node kinds without defining a single enum up-front.
+pub struct AnyContent {
+	value: Box<dyn DynContent>
+}
+
+impl AnyContent {
+	/// Erases the type of `value`.
+	pub fn new<U: Any + Debug + Clone>(value: U) -> Self {
+		Self {
+			value: Box::new(value)
+		}
+	}
+
+	/// Returns `true` if the inner value is of type `U`.
+	pub fn is<U: Any>(&self) -> bool {
+		self.value.as_any().is::<U>()
+	}
+
+	/// Get a reference to the inner value if it is of type `U`.
+	pub fn downcast_ref<U: Any>(&self) -> Option<&U> {
+		self.value.as_any().downcast_ref::<U>()
+	}
+
+	/// Get a mutable reference to the inner value if it is of type `U`.
+	pub fn downcast_mut<U: Any>(&mut self) -> Option<&mut U> {
+		self.value.as_any_mut().downcast_mut::<U>()
+	}
+
+	/// Consumes itself and returns the inner value if it is of type `U`, otherwise gives itself back.
+	pub fn downcast<U: Any>(self) -> Result<U, AnyContent> {
+		if !self.is::<U>() {
+			return Err(self);
+		}
+
+		Ok(*self.value.into_any().downcast::<U>().unwrap())
+	}
+}
+
+impl Clone for AnyContent {
+	fn clone(&self) -> Self {
+		Self {
+			value: self.value.clone_box()
+		}
+	}
+}
+
+impl Debug for AnyContent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.value.fmt(f)
+	}
+}
+
+/// A `Node` with a type-erased content, usually generated by the `any_node` macro.
+pub type AnyNode = Node<AnyContent>;
+
+impl Node<AnyContent> {
+	/// Returns `true` if the content is of type `U`.
+	pub fn is_content<U: Any>(&self) -> bool {
+		self.get().content.is::<U>()
+	}
+
+	/// Get a copy of the content if it is of type `U`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// #[derive(Debug, Clone)]
+	/// struct Element(&'static str);
+	///
+	/// #[derive(Debug, Clone)]
+	/// struct Text(String);
+	///
+	/// fn main() {
+	///		let node = any_node!(Element("p"), any_node!(Text("Hello".to_string())));
+	///		let text = node.child().unwrap();
+	///
+	///		assert!(node.is_content::<Element>());
+	///		assert!(text.downcast_content::<Element>().is_none());
+	///		assert_eq!(text.downcast_content::<Text>().unwrap().0, "Hello");
+	///
+	///		text.with_content_mut(|t: &mut Text| t.0.push('!'));
+	///		assert_eq!(text.with_content(|t: &Text| t.0.len()), Some(6));
+	/// }
+	/// ```
+	pub fn downcast_content<U: Any + Clone>(&self) -> Option<U> {
+		self.get().content.downcast_ref::<U>().cloned()
+	}
+
+	/// Copy-free alternative to `Node::downcast_content`: calls `f` with a reference
+	/// to the content if it is of type `U`.
+	pub fn with_content<U: Any, R, F: FnOnce(&U) -> R>(&self, f: F) -> Option<R> {
+		self.get().content.downcast_ref::<U>().map(f)
+	}
+
+	/// Calls `f` with a mutable reference to the content if it is of type `U`.
+	pub fn with_content_mut<U: Any, R, F: FnOnce(&mut U) -> R>(&self, f: F) -> Option<R> {
+		self.get_mut().content.downcast_mut::<U>().map(f)
+	}
+}
+
+/// Generate an `AnyNode` with any number of child nodes, erasing the type of the content.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let node = any_node!("div", any_node!(34), any_node!(2.5));
+///		assert!(node.get_last_child().unwrap().is_content::<f64>());
+/// }
+/// ```
+#[macro_export]
+macro_rules! any_node {
+	($content: expr $(,$node: expr)*) => {
+		hedel_rs::node!(hedel_rs::any::AnyContent::new($content) $(,$node)*)
+	}
+}
+
+/// Generate an enum content type for heterogeneous trees, along with a `From` impl
+/// for every variant payload and a typed accessor returning `Option<&Payload>`.
+/// Streamlines the common element/text/comment DOM pattern, when the kinds are known up-front.
+///
+/// Every variant holds a single payload and names its accessor after `=>`.
+/// The payload types must be distinct, as each one gets its own `From` impl.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// #[derive(Debug, Clone)]
+/// pub struct Element(&'static str);
+///
+/// #[derive(Debug, Clone)]
+/// pub struct Text(String);
+///
+/// hedel_variants! {
+///		#[derive(Debug, Clone)]
+///		pub enum Dom {
+///			Element(Element) => as_element,
+///			Text(Text) => as_text
+///		}
+/// }
+///
+/// fn main() {
+///		let node = node!(Dom::from(Element("p")), node!(Dom::from(Text("Hello".to_string()))));
+///
+///		assert_eq!(node.get().content.as_element().unwrap().0, "p");
+///		assert!(node.get().content.as_text().is_none());
+///		assert_eq!(node.child().unwrap().get().content.as_text().unwrap().0, "Hello");
+/// }
+/// ```
+#[macro_export]
+macro_rules! hedel_variants {
+	(
+		$(#[$meta: meta])*
+		$vis: vis enum $name: ident {
+			$($variant: ident($payload: ty) => $accessor: ident),* $(,)?
+		}
+	) => {
+		$(#[$meta])*
+		$vis enum $name {
+			$($variant($payload)),*
+		}
+
+		$(
+			impl From<$payload> for $name {
+				fn from(value: $payload) -> Self {
+					$name::$variant(value)
+				}
+			}
+		)*
+
+		impl $name {
+			$(
+				#[allow(unreachable_patterns)]
+				pub fn $accessor(&self) -> Option<&$payload> {
+					match self {
+						$name::$variant(value) => Some(value),
+						_ => None
+					}
+				}
+			)*
+		}
+	}
+}
@@ -0,0 +1,92 @@
+use std::{
+	fmt::Debug,
+	rc::Rc,
+};
+
+use proptest::{
+	prelude::*,
+	collection::vec,
+};
+
+use crate::{
+	node::{
+		Node,
+		AppendNode
+	},
+	list::List,
+};
+
+/// A generated subtree, turned into nodes once the strategy settled on a value.
+#[derive(Debug, Clone)]
+struct Shape<T>(T, Vec<Shape<T>>);
+
+fn shape<T, S>(depth: u32, branching: usize, gen_content: Rc<S>) -> BoxedStrategy<Shape<T>>
+where
+	T: Debug + Clone + 'static,
+	S: Strategy<Value = T> + 'static
+{
+	if depth == 0 {
+		return gen_content.prop_map(|content| Shape(content, Vec::new())).boxed();
+	}
+
+	(gen_content.clone(), vec(shape(depth - 1, branching, gen_content), 0..=branching))
+		.prop_map(|(content, children)| Shape(content, children))
+		.boxed()
+}
+
+fn build<T: Debug + Clone>(shape: Shape<T>) -> Node<T> {
+	let Shape(content, children) = shape;
+	let node = Node::new(content);
+	node.append_children(children.into_iter().map(build));
+	node
+}
+
+impl<T: Debug + Clone + 'static> Node<T> {
+	/// Get a proptest `Strategy` generating random trees: every node has up to `branching` children,
+	/// down to `depth` levels below the root, and its content generated by `gen_content`.
+	/// Shrinking removes children and shrinks the contents. The `Arbitrary` impl of `Node` uses it
+	/// with a depth and a branching of 4.
+	///
+	/// Requires the `test-support` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use proptest::test_runner::TestRunner;
+	///
+	/// fn main() {
+	///		let mut runner = TestRunner::default();
+	///
+	///		runner.run(&Node::strategy(3, 4, 0..100i32), |node| {
+	///			let height = node.aggregate(|_, children: &[u32]| 1 + children.iter().max().unwrap_or(&0));
+	///			assert!(height <= 4);
+	///			Ok(())
+	///		}).unwrap();
+	/// }
+	/// ```
+	pub fn strategy<S: Strategy<Value = T> + 'static>(depth: u32, branching: usize, gen_content: S) -> BoxedStrategy<Node<T>> {
+		shape(depth, branching, Rc::new(gen_content)).prop_map(build).boxed()
+	}
+}
+
+/// Generates trees up to 4 levels deep, with up to 4 children per node.
+impl<T: Arbitrary + Debug + Clone + 'static> Arbitrary for Node<T> {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+		Node::strategy(4, 4, any::<T>())
+	}
+}
+
+/// Generates linked lists of up to 4 root-level trees, refer to the `Arbitrary` impl of `Node`.
+impl<T: Arbitrary + Debug + Clone + 'static> Arbitrary for List<T> {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+		vec(any::<Node<T>>(), 0..=4).prop_map(List::from_nodes).boxed()
+	}
+}
@@ -0,0 +1,177 @@
+use std::{
+	collections::HashSet,
+	fmt::Debug,
+	rc::Rc,
+};
+
+use crate::{
+	node::Node,
+	list::List,
+	iter::next_in_subtree,
+};
+
+/// An inconsistency between the pointers of a linked list, found by `List::audit`.
+#[derive(Debug, Clone)]
+pub enum AuditIssue<T: Debug + Clone> {
+	/// `node` carries a `list` back-pointer, but it isn't a root-level node of the audited list.
+	/// Reported for the root-level nodes too, if their back-pointer is missing or points to another list.
+	StaleList(Node<T>),
+	/// `node` is reached through the `child` and `next` pointers of `expected` ( `None` at the root-level ),
+	/// but its `parent` pointer disagrees.
+	ParentMismatch { node: Node<T>, expected: Option<Node<T>> },
+	/// `node` follows `expected` ( `None` if it's the first of its siblings ),
+	/// but its `prev` pointer disagrees.
+	AsymmetricLink { node: Node<T>, expected: Option<Node<T>> },
+	/// `node` is referenced by the `parent` or `prev` pointer of `referenced_by`,
+	/// but it can't be reached from any root-level node.
+	Unreachable { node: Node<T>, referenced_by: Node<T> }
+}
+
+fn same<T: Debug + Clone>(a: &Option<Node<T>>, b: &Option<Node<T>>) -> bool {
+	match (a, b) {
+		(Some(a), Some(b)) => a.ptr_eq(b),
+		(None, None) => true,
+		_ => false
+	}
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Walks the whole linked list checking that every pointer agrees with the structure
+	/// reached by following `child` and `next`, and returns every inconsistency found.
+	/// An empty `Vec` means the linked list is consistent.
+	///
+	/// Meant as a diagnostic, e.g to track down why a subtree believed detached is still found by queries.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::audit::AuditIssue;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2), node!(3)));
+	///		assert!(list.audit().is_empty());
+	///
+	///		// `free` only re-sets the pointers inside the node: its parent still points to it.
+	///		let two = list.first().unwrap().child().unwrap();
+	///		two.free();
+	///
+	///		let issues = list.audit();
+	///		assert_eq!(issues.len(), 1);
+	///		assert!(matches!(&issues[0], AuditIssue::ParentMismatch { node, .. } if node.ptr_eq(&two)));
+	/// }
+	/// ```
+	pub fn audit(&self) -> Vec<AuditIssue<T>> {
+		let mut issues = Vec::new();
+		let mut reached = HashSet::new();
+		let mut visited = Vec::new();
+
+		let mut chains = vec![(self.first(), None)];
+
+		while let Some((chain, parent)) = chains.pop() {
+			let mut expected_prev: Option<Node<T>> = None;
+			let mut current = chain;
+
+			while let Some(node) = current {
+				// a cycle in the pointers, each node is only walked once.
+				if !reached.insert(Rc::as_ptr(&node.inner) as usize) {
+					break;
+				}
+
+				let is_root = parent.is_none();
+				let own_list = match node.get().list {
+					Some(ref list) => list.first.upgrade().is_some_and(|l| Rc::ptr_eq(&l, &self.first)),
+					None => false
+				};
+				let has_list = node.get().list.is_some();
+
+				if (has_list && !is_root) || (is_root && !own_list) {
+					issues.push(AuditIssue::StaleList(node.clone()));
+				}
+
+				if !same(&node.parent(), &parent) {
+					issues.push(AuditIssue::ParentMismatch { node: node.clone(), expected: parent.clone() });
+				}
+
+				if !same(&node.prev(), &expected_prev) {
+					issues.push(AuditIssue::AsymmetricLink { node: node.clone(), expected: expected_prev.clone() });
+				}
+
+				if node.child().is_some() {
+					chains.push((node.child(), Some(node.clone())));
+				}
+
+				current = node.next();
+				visited.push(node.clone());
+				expected_prev = Some(node);
+			}
+		}
+
+		for node in visited {
+			for target in [node.parent(), node.prev()].into_iter().flatten() {
+				if !reached.contains(&(Rc::as_ptr(&target.inner) as usize)) {
+					issues.push(AuditIssue::Unreachable { node: target, referenced_by: node.clone() });
+				}
+			}
+		}
+
+		issues
+	}
+}
+
+/// A node held alive by handles outside of its linked list, found by `Node::leak_report`.
+#[derive(Debug, Clone)]
+pub struct LeakEntry<T: Debug + Clone> {
+	pub node: Node<T>,
+	/// Number of strong handles to the node besides the one owned by the linked list
+	/// ( and, for the node the report was started from, the caller's handle ).
+	pub external: usize
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Walks `&self` and its descendants, listing the nodes with more strong handles than
+	/// the linked list accounts for. Those are kept alive by the application even if
+	/// their subtree gets detached, which helps finding who holds on to a removed fragment.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2), node!(3));
+	///		assert!(node.leak_report().is_empty());
+	///
+	///		let held = node.get_last_child().unwrap();
+	///		let report = node.leak_report();
+	///
+	///		assert_eq!(report.len(), 1);
+	///		assert!(report[0].node.ptr_eq(&held));
+	///		assert_eq!(report[0].external, 1);
+	/// }
+	/// ```
+	pub fn leak_report(&self) -> Vec<LeakEntry<T>> {
+		let mut report = Vec::new();
+
+		// the handle owned by the linked list, if `&self` is attached, plus the caller's one.
+		let attached = self.parent().is_some() || self.prev().is_some() || self.list().is_some();
+		let mut expected = usize::from(attached) + 1;
+		let mut next = Some(self.clone());
+
+		while let Some(node) = next {
+			// the handle in `node` is held by this walk.
+			let count = Rc::strong_count(&node.inner) - 1;
+
+			if count > expected {
+				report.push(LeakEntry { node: node.clone(), external: count - expected });
+			}
+
+			next = next_in_subtree(&node, self);
+			expected = 1;
+		}
+
+		report
+	}
+}
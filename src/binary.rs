@@ -0,0 +1,108 @@
+use std::fmt::Debug;
+
+use serde::{
+	Serialize,
+	de::DeserializeOwned
+};
+
+use crate::{
+	node::Node,
+	iter::next_in_subtree,
+	errors::HedelError,
+};
+
+impl<T: Debug + Clone + Serialize> Node<T> {
+	/// Encodes the subtree of `&self` into a compact binary form: every node is written in
+	/// pre-order as its content followed by the number of its children.
+	///
+	/// Requires the `postcard` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(3)), node!(4));
+	///		let bytes = node.to_bytes().unwrap();
+	///
+	///		let decoded = Node::<i32>::from_bytes(&bytes).unwrap();
+	///		assert_eq!(decoded.first_leaf().unwrap().to_content(), 3);
+	///		assert_eq!(decoded.get_last_child().unwrap().to_content(), 4);
+	/// }
+	/// ```
+	pub fn to_bytes(&self) -> Result<Vec<u8>, HedelError> {
+		let mut entries: Vec<(T, usize)> = Vec::new();
+		let mut next = Some(self.clone());
+
+		while let Some(node) = next {
+			next = next_in_subtree(&node, self);
+
+			let mut count = 0;
+			let mut child = node.child();
+
+			while let Some(c) = child {
+				count += 1;
+				child = c.next();
+			}
+
+			entries.push((node.get().content.clone(), count));
+		}
+
+		postcard::to_allocvec(&entries).map_err(|e| HedelError::Encoding(e.to_string()))
+	}
+}
+
+impl<T: Debug + Clone + DeserializeOwned> Node<T> {
+	/// Decodes a subtree encoded by `Node::to_bytes`, returning its root.
+	///
+	/// Requires the `postcard` feature.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Node<T>, HedelError> {
+		let entries: Vec<(T, usize)> = postcard::from_bytes(bytes)
+			.map_err(|e| HedelError::Encoding(e.to_string()))?;
+
+		let mut entries = entries.into_iter();
+
+		let (content, count) = entries.next()
+			.ok_or_else(|| HedelError::Encoding("no root node".to_string()))?;
+
+		let root = Node::new(content);
+
+		// the open nodes, each paired with the number of children still to be read
+		// and its last child read so far.
+		let mut open: Vec<(Node<T>, usize, Option<Node<T>>)> = vec![(root.clone(), count, None)];
+
+		for (content, count) in entries {
+			while let Some((_, 0, _)) = open.last() {
+				open.pop();
+			}
+
+			let (parent, remaining, last) = open.last_mut()
+				.ok_or_else(|| HedelError::Encoding("more nodes than declared children".to_string()))?;
+
+			*remaining -= 1;
+
+			let node = Node::new(content);
+
+			node.get_mut().parent = Some(parent.downgrade());
+
+			match last {
+				Some(last) => {
+					last.get_mut().next = Some(node.clone());
+					node.get_mut().prev = Some(last.downgrade());
+				},
+				None => parent.get_mut().child = Some(node.clone())
+			}
+
+			*last = Some(node.clone());
+			open.push((node, count, None));
+		}
+
+		if open.iter().any(|(_, remaining, _)| *remaining > 0) {
+			return Err(HedelError::Encoding("fewer nodes than declared children".to_string()));
+		}
+
+		Ok(root)
+	}
+}
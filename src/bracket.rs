@@ -0,0 +1,158 @@
+use std::{
+	fmt::{
+		Debug,
+		Display
+	},
+	str::FromStr,
+};
+
+use crate::{
+	node::Node,
+	builder::TreeBuilder,
+	iter::{
+		IterNode,
+		NodeEvent
+	},
+	errors::HedelError,
+};
+
+/// Parses a single content, `start` being the byte offset of `label` in the whole input.
+fn parse_content<T: FromStr>(label: &str, start: usize) -> Result<T, HedelError>
+where
+	T::Err: Debug
+{
+	let trimmed = label.trim();
+
+	if trimmed.is_empty() {
+		return Err(HedelError::Parse(start, "missing content".to_string()));
+	}
+
+	trimmed.parse::<T>().map_err(|err| HedelError::Parse(start, format!("invalid content `{}`: {:?}", trimmed, err)))
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Parses a tree written in bracket notation: every node is its content, followed by
+	/// its children between parentheses and separated by commas, e.g `1(2,3(4,5))`.
+	/// Whitespace around the contents is ignored. A terse format to build test fixtures or log shapes.
+	///
+	/// Returns `HedelError::Parse` with the byte offset of the error, if the input is malformed
+	/// or a content can't be parsed.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = Node::<i32>::parse_bracket("1(2, 3(4, 5))").unwrap();
+	///
+	///		assert_eq!(node.get_last_child().unwrap().get_last_child().unwrap().to_content(), 5);
+	///		assert_eq!(node.to_bracket_string(), "1(2,3(4,5))");
+	///
+	///		assert!(Node::<i32>::parse_bracket("1(2,").is_err());
+	///		assert!(Node::<i32>::parse_bracket("1(x)").is_err());
+	/// }
+	/// ```
+	pub fn parse_bracket(input: &str) -> Result<Node<T>, HedelError>
+	where
+		T: FromStr,
+		T::Err: Debug
+	{
+		let mut builder = TreeBuilder::new();
+		let mut label_start = 0;
+		// `true` while reading a content, `false` right after a `)`.
+		let mut in_label = true;
+
+		for (i, c) in input.char_indices() {
+			match c {
+				'(' | ',' | ')' => {
+					if in_label {
+						builder.start_node(parse_content::<T>(&input[label_start..i], label_start)?);
+					} else if c == '(' {
+						return Err(HedelError::Parse(i, "unexpected `(`".to_string()));
+					}
+
+					match c {
+						'(' => in_label = true,
+						',' => {
+							builder.end_node();
+
+							if builder.depth() == 0 {
+								return Err(HedelError::Parse(i, "more than one root node".to_string()));
+							}
+
+							in_label = true;
+						},
+						_ => {
+							builder.end_node();
+
+							if builder.depth() == 0 {
+								return Err(HedelError::Parse(i, "unbalanced `)`".to_string()));
+							}
+
+							in_label = false;
+						}
+					}
+
+					label_start = i + c.len_utf8();
+				},
+				c if !in_label && !c.is_whitespace() => {
+					return Err(HedelError::Parse(i, format!("unexpected `{}`", c)));
+				},
+				_ => {}
+			}
+		}
+
+		if in_label {
+			builder.start_node(parse_content::<T>(&input[label_start..], label_start)?);
+		}
+
+		builder.end_node();
+
+		if builder.depth() != 0 {
+			return Err(HedelError::Parse(input.len(), "unclosed `(`".to_string()));
+		}
+
+		// exactly one root-level node was built.
+		let node = builder.finish().first.get_mut().take().unwrap();
+		node.get_mut().list = None;
+		Ok(node)
+	}
+
+	/// Writes `&self` and its descendants in bracket notation, refer to `Node::parse_bracket`.
+	/// The output only parses back if no content is displayed empty or containing `(`, `,` or `)`.
+	pub fn to_bracket_string(&self) -> String
+	where
+		T: Display
+	{
+		let mut output = String::new();
+		let mut last_started = false;
+		let mut first = true;
+
+		for event in self.events() {
+			match event {
+				NodeEvent::Start(content) => {
+					if last_started {
+						output.push('(');
+					} else if !first {
+						output.push(',');
+					}
+
+					output.push_str(&content.to_string());
+					last_started = true;
+					first = false;
+				},
+				NodeEvent::End => {
+					if !last_started {
+						output.push(')');
+					}
+
+					last_started = false;
+				}
+			}
+		}
+
+		output
+	}
+}
@@ -0,0 +1,128 @@
+use std::fmt::Debug;
+
+use crate::{
+	node::Node,
+	list::List,
+};
+
+/// Incrementally constructs a correctly linked `List`, one event at a time.
+/// Designed as a target for streaming parsers ( HTML, JSON, log folding ),
+/// which know when a node opens and closes but don't hold the whole tree.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::builder::TreeBuilder;
+///
+/// fn main() {
+///		let mut builder = TreeBuilder::new();
+///
+///		builder.start_node("ul");
+///		builder.add_leaf("li");
+///		builder.add_leaf("li");
+///		builder.end_node();
+///		builder.add_leaf("p");
+///
+///		let list = builder.finish();
+///		let ul = list.first().unwrap();
+///
+///		assert_eq!(ul.get_last_child().unwrap().parent().unwrap().to_content(), "ul");
+///		assert_eq!(ul.next().unwrap().to_content(), "p");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TreeBuilder<T: Debug + Clone> {
+	/// The currently open nodes, each paired with its last child so far.
+	open: Vec<(Node<T>, Option<Node<T>>)>,
+	first: Option<Node<T>>,
+	last: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Default for TreeBuilder<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Debug + Clone> TreeBuilder<T> {
+
+	/// Builds an empty `TreeBuilder`.
+	pub fn new() -> Self {
+		Self {
+			open: Vec::new(),
+			first: None,
+			last: None
+		}
+	}
+
+	/// Number of nodes currently open.
+	pub fn depth(&self) -> usize {
+		self.open.len()
+	}
+
+	fn link(&mut self, node: &Node<T>) {
+		match self.open.last_mut() {
+			Some((parent, last_child)) => {
+				node.get_mut().parent = Some(parent.downgrade());
+
+				match last_child {
+					Some(last) => {
+						last.get_mut().next = Some(node.clone());
+						node.get_mut().prev = Some(last.downgrade());
+					},
+					None => {
+						parent.get_mut().child = Some(node.clone());
+					}
+				}
+
+				*last_child = Some(node.clone());
+			},
+			None => {
+				match self.last {
+					Some(ref last) => {
+						last.get_mut().next = Some(node.clone());
+						node.get_mut().prev = Some(last.downgrade());
+					},
+					None => {
+						self.first = Some(node.clone());
+					}
+				}
+
+				self.last = Some(node.clone());
+			}
+		}
+	}
+
+	/// Appends a new node at the current position and opens it: the following nodes
+	/// become its children until `TreeBuilder::end_node` is called.
+	pub fn start_node(&mut self, content: T) -> Node<T> {
+		let node = Node::new(content);
+		self.link(&node);
+		self.open.push((node.clone(), None));
+		node
+	}
+
+	/// Closes the last opened node, returning it.
+	/// Returns `None` if there isn't any open node.
+	pub fn end_node(&mut self) -> Option<Node<T>> {
+		self.open.pop().map(|(node, _)| node)
+	}
+
+	/// Appends a new node without children at the current position.
+	pub fn add_leaf(&mut self, content: T) -> Node<T> {
+		let node = Node::new(content);
+		self.link(&node);
+		node
+	}
+
+	/// Consumes the builder and returns the built `List`.
+	/// Nodes still open are implicitly closed.
+	pub fn finish(self) -> List<T> {
+		match self.first {
+			Some(first) => List::new(first),
+			None => List::empty()
+		}
+	}
+}
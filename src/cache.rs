@@ -0,0 +1,151 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+	hash::Hash,
+	rc::Rc,
+};
+
+use crate::node::{
+	Node,
+	WeakNode,
+	NodeCollection
+};
+
+/// The result of a query, along with the generation of the linked list it was computed at.
+#[derive(Debug)]
+struct CachedQuery<T: Debug + Clone> {
+	scope: WeakNode<T>,
+	/// Address of the generation counter of the linked list, so moving the scope into another one invalidates.
+	list: usize,
+	generation: u64,
+	nodes: Vec<WeakNode<T>>
+}
+
+/// Caches the results of repeated queries, e.g evaluated every frame of a render loop.
+/// Results are keyed by a query key of your choice and the scope node the query ran on,
+/// and get recomputed once the linked list of the scope changed structurally ( refer to `List::generation` ).
+///
+/// Only weak pointers are stored, so the cache doesn't keep any node alive.
+/// Changes to the contents are not structural: call `QueryCache::invalidate` if a query depends on them.
+/// Scopes which don't belong to a `List` have no generation, and their queries are never cached.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::cache::QueryCache;
+///
+/// struct Odd;
+///
+/// impl CompareNode<i32> for Odd {
+///		fn compare(&self, node: &Node<i32>) -> bool {
+///			node.get().content % 2 == 1
+///		}
+/// }
+///
+/// fn main() {
+///		let list = list!(node!(0, node!(1), node!(2)));
+///		let root = list.first().unwrap();
+///		let mut cache = QueryCache::new();
+///		let mut runs = 0;
+///
+///		for _ in 0..2 {
+///			let odd = cache.get_or_insert_with("odd", &root, |scope| {
+///				runs += 1;
+///				scope.collect_leaves(&Odd)
+///			});
+///			assert_eq!(odd.into_nodes().len(), 1);
+///		}
+///
+///		assert_eq!(runs, 1);
+///
+///		// a structural change invalidates the cached result.
+///		root.append_child(node!(3));
+///
+///		let odd = cache.get_or_insert_with("odd", &root, |scope| {
+///			runs += 1;
+///			scope.collect_leaves(&Odd)
+///		});
+///
+///		assert_eq!(odd.into_nodes().len(), 2);
+///		assert_eq!(runs, 2);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct QueryCache<K: Eq + Hash, T: Debug + Clone> {
+	entries: HashMap<(K, usize), CachedQuery<T>>
+}
+
+impl<K: Eq + Hash, T: Debug + Clone> QueryCache<K, T> {
+	pub fn new() -> Self {
+		Self {
+			entries: HashMap::new()
+		}
+	}
+
+	/// Get the cached result of the query `key` on `scope`, or run `query` on `scope` and cache its result
+	/// if there is none or the linked list changed since.
+	pub fn get_or_insert_with<F: FnOnce(&Node<T>) -> NodeCollection<T>>(&mut self, key: K, scope: &Node<T>, query: F) -> NodeCollection<T> {
+		let list = match scope.root_list() {
+			Some(list) => list,
+			None => return query(scope)
+		};
+
+		let address = Rc::as_ptr(&list.generation) as usize;
+		let generation = list.generation();
+		let key = (key, Rc::as_ptr(&scope.inner) as usize);
+
+		if let Some(cached) = self.entries.get(&key) {
+			let fresh = cached.list == address
+				&& cached.generation == generation
+				// the address of a dropped scope could have been reused by another node.
+				&& cached.scope.upgrade().is_some_and(|s| s.ptr_eq(scope));
+
+			if fresh {
+				let nodes: Option<Vec<Node<T>>> = cached.nodes.iter().map(|n| n.upgrade()).collect();
+
+				if let Some(nodes) = nodes {
+					return NodeCollection::from_vec(nodes);
+				}
+			}
+		}
+
+		let result = query(scope);
+
+		self.entries.insert(key, CachedQuery {
+			scope: scope.downgrade(),
+			list: address,
+			generation,
+			nodes: result.as_nodes().iter().map(|n| n.downgrade()).collect()
+		});
+
+		result
+	}
+
+	/// Drops every cached result.
+	pub fn invalidate(&mut self) {
+		self.entries.clear();
+	}
+
+	/// Drops the cached results of the query `key`, on any scope.
+	pub fn invalidate_query(&mut self, key: &K) {
+		self.entries.retain(|(k, _), _| k != key);
+	}
+
+	/// Returns the number of cached results, stale ones included.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Returns `true` if there is no cached result.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+}
+
+impl<K: Eq + Hash, T: Debug + Clone> Default for QueryCache<K, T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -4,11 +4,7 @@ use std::{
 		Deref,
 		DerefMut
 	},
-	cell::{
-		Cell,
-		UnsafeCell
-	},
-	ptr::NonNull,
+	cell::Cell,
 	num::NonZeroUsize
 };
 
@@ -27,10 +23,20 @@ pub enum BorrowFlag {
 
 /// A safe custom `RefCell-like` cell, based on `UnsafeCell`, and relying on a `BorrowFlag`
 /// for runtime borrow checking.
+///
+/// The borrow checking can be delegated to an external implementation instead, by enabling
+/// the `parking_lot` feature ( backed by `parking_lot::RwLock` ) or the `refcell` feature
+/// ( backed by `std::cell::RefCell` ). When both are enabled `parking_lot` is used.
+/// The API and the errors stay the same with every backend.
+///
+/// Like `Mutex`, the cell gets poisoned if a panic unwinds while a `RefMutHedel` is alive,
+/// as the panic might have interrupted a multi-pointer update half-way: further borrows
+/// return `HedelError::Poisoned` until `HedelCell::clear_poison` is called.
+/// A `RefMutHedel` taken while already unwinding ( e.g by a `Drop` impl ) doesn't poison the cell.
 #[derive(Debug)]
 pub struct HedelCell<T: Debug> {
-	flag: Cell<BorrowFlag>,
-	cell: UnsafeCell<T>
+	poisoned: Cell<bool>,
+	cell: backend::Lock<T>
 }
 
 impl<T: Debug> HedelCell<T> {
@@ -49,8 +55,8 @@ impl<T: Debug> HedelCell<T> {
 	/// ```
 	pub fn new(value: T) -> Self {
 		Self {
-			flag: Cell::new(BorrowFlag::None),
-			cell: UnsafeCell::<T>::new(value)
+			poisoned: Cell::new(false),
+			cell: backend::Lock::new(value)
 		}
 	}
 
@@ -71,28 +77,18 @@ impl<T: Debug> HedelCell<T> {
 	///		println!("{:?}", borrow); // prints 56
 	/// }
 	/// ```
-	pub fn try_get(&self) -> Result<RefHedel<T>, HedelError> {
-		
-		match self.flag.get() {
-			BorrowFlag::None => {
-				self.flag.replace(BorrowFlag::Shared(NonZeroUsize::new(1).unwrap()));
-			},
-			BorrowFlag::Shared(n) => {
-				self.flag.replace(BorrowFlag::Shared(n.saturating_add(1)));
-			},
-			_ => {
-				return Err(HedelError::SharedBorrow);
-			}
+	pub fn try_get(&self) -> Result<RefHedel<'_, T>, HedelError> {
+		if self.poisoned.get() {
+			return Err(HedelError::Poisoned);
 		}
 
 		Ok(RefHedel {
-			value: unsafe { &*self.cell.get() },
-			flag: &self.flag
+			guard: self.cell.try_shared()?
 		})
 	}
 	
 	/// Guarantees to return `RefHedel` or panics!
-	pub fn get(&self) -> RefHedel<T> {
+	pub fn get(&self) -> RefHedel<'_, T> {
 		self.try_get().unwrap()
 	}
 
@@ -115,30 +111,78 @@ impl<T: Debug> HedelCell<T> {
 	///		// this would panic
 	/// 	// println!("{:?}", cell.get()); 
 	///	}
-	 
 	/// ```
 	pub fn try_get_mut<'a>(&'a self) -> Result<RefMutHedel<'a, T>, HedelError> {
-		if let BorrowFlag::None = self.flag.get() {
-
-			self.flag.replace(BorrowFlag::Exclusive);
-
-			let value = match NonNull::<T>::new(UnsafeCell::raw_get(&self.cell as *const UnsafeCell::<T>)) {
-				Some(value) => value,
-				None => return Err(HedelError::InvalidNonNull) 
-			};
+		if self.poisoned.get() {
+			return Err(HedelError::Poisoned);
+		}
 
-			return Ok(RefMutHedel::<T> {
-				flag: &self.flag,
-				value 
-			});
-		} Err(HedelError::MutBorrow_)
+		Ok(RefMutHedel {
+			guard: self.cell.try_exclusive()?,
+			poisoned: &self.poisoned,
+			panicking: std::thread::panicking()
+		})
 	}
 
 	/// Guarantees to return `RefMutHedel` or panics!
-	pub fn get_mut(&self) -> RefMutHedel<T> {
+	pub fn get_mut(&self) -> RefMutHedel<'_, T> {
 		self.try_get_mut().unwrap()
 	}
 
+	/// Returns `true` if a panic occurred while a `RefMutHedel` to the cell was alive.
+	pub fn is_poisoned(&self) -> bool {
+		self.poisoned.get()
+	}
+
+	/// Clears the poisoning, allowing to borrow the value again once it got checked or repaired.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::cell::HedelCell;
+	/// use hedel_rs::errors::HedelError;
+	/// use std::panic::{
+	///		catch_unwind,
+	///		AssertUnwindSafe
+	/// };
+	///
+	/// fn main() {
+	///		let cell = HedelCell::new(vec![1, 2]);
+	///
+	///		let _ = catch_unwind(AssertUnwindSafe(|| {
+	///			let mut borrow = cell.get_mut();
+	///			borrow.push(3);
+	///			panic!("interrupted half-way");
+	///		}));
+	///
+	///		assert!(cell.is_poisoned());
+	///		assert!(matches!(cell.try_get(), Err(HedelError::Poisoned)));
+	///
+	///		cell.clear_poison();
+	///		assert_eq!(*cell.get(), vec![1, 2, 3]);
+	///
+	///		// a borrow taken while already unwinding doesn't poison the cell.
+	///		struct Cleanup<'a>(&'a HedelCell<Vec<i32>>);
+	///
+	///		impl<'a> Drop for Cleanup<'a> {
+	///			fn drop(&mut self) {
+	///				self.0.get_mut().clear();
+	///			}
+	///		}
+	///
+	///		let _ = catch_unwind(AssertUnwindSafe(|| {
+	///			let _cleanup = Cleanup(&cell);
+	///			panic!("unwinding");
+	///		}));
+	///
+	///		assert!(!cell.is_poisoned());
+	///		assert!(cell.get().is_empty());
+	/// }
+	/// ```
+	pub fn clear_poison(&self) {
+		self.poisoned.set(false);
+	}
+
 	/// Consumes itself and returns the inner value
 	pub fn into_inner(self) -> T {
 		self.cell.into_inner()
@@ -149,42 +193,24 @@ impl<T: Debug> HedelCell<T> {
 /// Has to be built by calling `HedelCell::get`.
 #[derive(Debug)]
 pub struct RefHedel<'a, T: Debug> {
-	value: &'a T,
-	flag: &'a Cell<BorrowFlag>
+	guard: backend::Shared<'a, T>
 }
 
 /// Automatically dereferences `RefHedel` to &T.
 impl<'a, T: Debug> Deref for RefHedel<'a, T> {
 	type Target = T;
 	fn deref(&self) -> &T {
-		self.value
-	}
-}
-
-/// SAFETY: when a `RefHedel` is dropped, the shared reference counter
-/// is diminished by 1. To prevent it to reach 0 it is set to None.
-impl<'a, T: Debug> Drop for RefHedel<'a, T> {
-	fn drop(&mut self) {
-		match self.flag.get() {
-			BorrowFlag::Shared(n) => {
-				if n.get() > 1 {
-					self.flag.replace(BorrowFlag::Shared(NonZeroUsize::new(n.get() - 1).unwrap()));
-				} else {
-					self.flag.replace(BorrowFlag::None);
-				}
-			},
-			_ => {
-				unreachable!("Before a `RefHedel` gets dropped, there should be a `BorrowFlag::Shared(_)`");
-			}
-		}
+		&self.guard
 	}
 }
 
 /// Represents a mutable reference to a `HedelCell`.
 /// Has to be built by calling `HedelCell::get`.
 pub struct RefMutHedel<'a, T: Debug> {
-	value: NonNull<T>,
-	flag: &'a Cell<BorrowFlag>
+	guard: backend::Exclusive<'a, T>,
+	poisoned: &'a Cell<bool>,
+	/// Whether the thread was already panicking when the borrow was taken.
+	panicking: bool
 }
 
 /// Automatically dereferences `RefMutHedel` to &T.
@@ -192,7 +218,7 @@ impl<'a, T: Debug> Deref for RefMutHedel<'a, T> {
 	type Target = T;
 	
 	fn deref(&self) -> &T {
-		unsafe { self.value.as_ref() } 
+		&self.guard
 	}
 }
 
@@ -200,14 +226,261 @@ impl<'a, T: Debug> Deref for RefMutHedel<'a, T> {
 impl<'a, T: Debug> DerefMut for RefMutHedel<'a, T> {
 
     fn deref_mut(&mut self) -> &mut T {
-        unsafe { self.value.as_mut() }
+        &mut self.guard
     }
 }
 
-/// SAFETY: before `RefMutHedel` gets dropped, it changes the flag to `BorrowFlag::None`,
-/// meaning that now, shared immutable references are avaiable.
+/// If dropped while unwinding from a panic which started after the borrow was taken, it poisons the cell.
+/// The borrow itself is released right after, when the guard gets dropped.
 impl<'a, T: Debug> Drop for RefMutHedel<'a, T> {
 	fn drop(&mut self) {
-		self.flag.replace(BorrowFlag::None);
+		if !self.panicking && std::thread::panicking() {
+			self.poisoned.set(true);
+		}
+	}
+}
+
+/// The hand-rolled borrow checking, used when no other backend is enabled.
+#[cfg(not(any(feature = "parking_lot", feature = "refcell")))]
+mod backend {
+	use std::{
+		fmt::Debug,
+		ops::{
+			Deref,
+			DerefMut
+		},
+		cell::{
+			Cell,
+			UnsafeCell
+		},
+		ptr::NonNull,
+		num::NonZeroUsize
+	};
+
+	use super::BorrowFlag;
+	use crate::errors::{
+		HedelError,
+		BorrowKind
+	};
+
+	#[derive(Debug)]
+	pub struct Lock<T: Debug> {
+		flag: Cell<BorrowFlag>,
+		cell: UnsafeCell<T>
+	}
+
+	impl<T: Debug> Lock<T> {
+		pub fn new(value: T) -> Self {
+			Self {
+				flag: Cell::new(BorrowFlag::None),
+				cell: UnsafeCell::<T>::new(value)
+			}
+		}
+
+		pub fn try_shared(&self) -> Result<Shared<'_, T>, HedelError> {
+			match self.flag.get() {
+				BorrowFlag::None => {
+					self.flag.replace(BorrowFlag::Shared(NonZeroUsize::new(1).unwrap()));
+				},
+				BorrowFlag::Shared(n) => {
+					self.flag.replace(BorrowFlag::Shared(n.saturating_add(1)));
+				},
+				BorrowFlag::Exclusive => {
+					return Err(HedelError::BorrowConflict { requested: BorrowKind::Shared, held: BorrowKind::Mutable });
+				}
+			}
+
+			Ok(Shared {
+				value: unsafe { &*self.cell.get() },
+				flag: &self.flag
+			})
+		}
+
+		/// Sets the flag to `BorrowFlag::Exclusive`.
+		pub fn try_exclusive(&self) -> Result<Exclusive<'_, T>, HedelError> {
+			if let BorrowFlag::None = self.flag.get() {
+
+				self.flag.replace(BorrowFlag::Exclusive);
+
+				let value = match NonNull::<T>::new(UnsafeCell::raw_get(&self.cell as *const UnsafeCell::<T>)) {
+					Some(value) => value,
+					None => return Err(HedelError::InvalidNonNull) 
+				};
+
+				return Ok(Exclusive::<T> {
+					flag: &self.flag,
+					value 
+				});
+			}
+
+			let held = match self.flag.get() {
+				BorrowFlag::Shared(_) => BorrowKind::Shared,
+				_ => BorrowKind::Mutable
+			};
+
+			Err(HedelError::BorrowConflict { requested: BorrowKind::Mutable, held })
+		}
+
+		pub fn into_inner(self) -> T {
+			self.cell.into_inner()
+		}
+	}
+
+	#[derive(Debug)]
+	pub struct Shared<'a, T: Debug> {
+		value: &'a T,
+		flag: &'a Cell<BorrowFlag>
+	}
+
+	impl<'a, T: Debug> Deref for Shared<'a, T> {
+		type Target = T;
+		fn deref(&self) -> &T {
+			self.value
+		}
+	}
+
+	/// SAFETY: when a `Shared` is dropped, the shared reference counter
+	/// is diminished by 1. To prevent it to reach 0 it is set to None.
+	impl<'a, T: Debug> Drop for Shared<'a, T> {
+		fn drop(&mut self) {
+			match self.flag.get() {
+				BorrowFlag::Shared(n) => {
+					if n.get() > 1 {
+						self.flag.replace(BorrowFlag::Shared(NonZeroUsize::new(n.get() - 1).unwrap()));
+					} else {
+						self.flag.replace(BorrowFlag::None);
+					}
+				},
+				_ => {
+					unreachable!("Before a `RefHedel` gets dropped, there should be a `BorrowFlag::Shared(_)`");
+				}
+			}
+		}
+	}
+
+	pub struct Exclusive<'a, T: Debug> {
+		value: NonNull<T>,
+		flag: &'a Cell<BorrowFlag>
+	}
+
+	impl<'a, T: Debug> Deref for Exclusive<'a, T> {
+		type Target = T;
+		
+		fn deref(&self) -> &T {
+			unsafe { self.value.as_ref() } 
+		}
+	}
+
+	impl<'a, T: Debug> DerefMut for Exclusive<'a, T> {
+		fn deref_mut(&mut self) -> &mut T {
+			unsafe { self.value.as_mut() }
+		}
+	}
+
+	/// SAFETY: before `Exclusive` gets dropped, it changes the flag to `BorrowFlag::None`,
+	/// meaning that now, shared immutable references are avaiable.
+	impl<'a, T: Debug> Drop for Exclusive<'a, T> {
+		fn drop(&mut self) {
+			self.flag.replace(BorrowFlag::None);
+		}
+	}
+}
+
+/// The borrow checking of `parking_lot::RwLock`, without ever blocking: a conflicting borrow fails right away.
+#[cfg(feature = "parking_lot")]
+mod backend {
+	use std::fmt::Debug;
+
+	use parking_lot::{
+		RwLock,
+		RwLockReadGuard,
+		RwLockWriteGuard
+	};
+
+	use crate::errors::{
+		HedelError,
+		BorrowKind
+	};
+
+	pub type Shared<'a, T> = RwLockReadGuard<'a, T>;
+	pub type Exclusive<'a, T> = RwLockWriteGuard<'a, T>;
+
+	#[derive(Debug)]
+	pub struct Lock<T: Debug>(RwLock<T>);
+
+	impl<T: Debug> Lock<T> {
+		pub fn new(value: T) -> Self {
+			Self(RwLock::new(value))
+		}
+
+		pub fn try_shared(&self) -> Result<Shared<'_, T>, HedelError> {
+			self.0.try_read()
+				.ok_or(HedelError::BorrowConflict { requested: BorrowKind::Shared, held: BorrowKind::Mutable })
+		}
+
+		pub fn try_exclusive(&self) -> Result<Exclusive<'_, T>, HedelError> {
+			self.0.try_write().ok_or_else(|| {
+				let held = match self.0.is_locked_exclusive() {
+					true => BorrowKind::Mutable,
+					false => BorrowKind::Shared
+				};
+
+				HedelError::BorrowConflict { requested: BorrowKind::Mutable, held }
+			})
+		}
+
+		pub fn into_inner(self) -> T {
+			self.0.into_inner()
+		}
+	}
+}
+
+/// The borrow checking of `std::cell::RefCell`.
+#[cfg(all(feature = "refcell", not(feature = "parking_lot")))]
+mod backend {
+	use std::{
+		fmt::Debug,
+		cell::{
+			RefCell,
+			Ref,
+			RefMut
+		}
+	};
+
+	use crate::errors::{
+		HedelError,
+		BorrowKind
+	};
+
+	pub type Shared<'a, T> = Ref<'a, T>;
+	pub type Exclusive<'a, T> = RefMut<'a, T>;
+
+	#[derive(Debug)]
+	pub struct Lock<T: Debug>(RefCell<T>);
+
+	impl<T: Debug> Lock<T> {
+		pub fn new(value: T) -> Self {
+			Self(RefCell::new(value))
+		}
+
+		pub fn try_shared(&self) -> Result<Shared<'_, T>, HedelError> {
+			self.0.try_borrow()
+				.map_err(|_| HedelError::BorrowConflict { requested: BorrowKind::Shared, held: BorrowKind::Mutable })
+		}
+
+		pub fn try_exclusive(&self) -> Result<Exclusive<'_, T>, HedelError> {
+			self.0.try_borrow_mut().map_err(|_| {
+				let held = match self.0.try_borrow() {
+					Ok(_) => BorrowKind::Shared,
+					Err(_) => BorrowKind::Mutable
+				};
+
+				HedelError::BorrowConflict { requested: BorrowKind::Mutable, held }
+			})
+		}
+
+		pub fn into_inner(self) -> T {
+			self.0.into_inner()
+		}
 	}
 }
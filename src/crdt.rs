@@ -0,0 +1,301 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+	rc::Rc,
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+	Serialize,
+	Deserialize
+};
+
+use crate::{
+	node::{
+		Node,
+		AppendNode
+	},
+	list::List,
+	range::unlink_run,
+	oplog::{
+		record,
+		Change
+	},
+};
+
+/// Stable identifier of a node in a `CrdtTree`: a Lamport timestamp paired with the replica
+/// which created the node. Identifiers are totally ordered, by counter and then by replica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpId {
+	pub counter: u64,
+	pub replica: u64
+}
+
+/// An operation exchanged between the replicas of a `CrdtTree`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CrdtOp<T> {
+	/// Inserts a node under `parent` ( `None` for the root-level ), right after the sibling `after`
+	/// ( `None` for the first position ), as seen by the replica which created it.
+	Insert { id: OpId, parent: Option<OpId>, after: Option<OpId>, content: T },
+	/// Removes a node, which is kept as a tombstone so concurrent operations referring to it still apply.
+	Remove { id: OpId }
+}
+
+#[derive(Debug)]
+struct Entry<T: Debug + Clone> {
+	node: Node<T>,
+	parent: Option<OpId>,
+	tombstone: bool,
+	/// The children in order, tombstones included.
+	children: Vec<OpId>
+}
+
+/// A replicated ordered tree: every replica applies its local changes and the operations
+/// of the other replicas in any order, and all of them converge to the same `List`.
+///
+/// Every node gets a stable `OpId`. Siblings are ordered like in RGA: a node is inserted after
+/// a given sibling, and concurrent insertions at the same position are sorted by descending id.
+/// Removed nodes are tombstoned: they leave the visible `List` but keep anchoring concurrent operations.
+/// Operations depending on nodes not received yet are held back until they can apply.
+///
+/// The visible `List` must only be changed through the `CrdtTree`. Requires the `crdt` feature.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::crdt::CrdtTree;
+///
+/// fn main() {
+///		let mut a = CrdtTree::new(1);
+///		let root = a.insert(None, None, "doc");
+///
+///		let mut b = CrdtTree::new(2);
+///		b.merge(&a);
+///
+///		// concurrent edits on both replicas.
+///		let title = a.insert(Some(root), None, "title");
+///		b.insert(Some(root), None, "footer");
+///		a.remove(title);
+///		a.insert(Some(root), None, "heading");
+///
+///		a.merge(&b);
+///		b.merge(&a);
+///
+///		fn shape(tree: &CrdtTree<&'static str>) -> Vec<&'static str> {
+///			let doc = tree.list().first().unwrap();
+///			doc.children_range(0..usize::MAX).iter().map(|n| n.to_content()).collect()
+///		}
+///
+///		assert_eq!(shape(&a), shape(&b));
+///		assert_eq!(shape(&a), vec!["heading", "footer"]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CrdtTree<T: Debug + Clone> {
+	replica: u64,
+	clock: u64,
+	list: List<T>,
+	entries: HashMap<OpId, Entry<T>>,
+	ids: HashMap<usize, OpId>,
+	/// The root-level nodes in order, tombstones included.
+	roots: Vec<OpId>,
+	log: Vec<CrdtOp<T>>,
+	pending: Vec<CrdtOp<T>>
+}
+
+impl<T: Debug + Clone> CrdtTree<T> {
+	/// Builds an empty replica. Every replica must have a different `replica` id.
+	pub fn new(replica: u64) -> Self {
+		Self {
+			replica,
+			clock: 0,
+			list: List::empty(),
+			entries: HashMap::new(),
+			ids: HashMap::new(),
+			roots: Vec::new(),
+			log: Vec::new(),
+			pending: Vec::new()
+		}
+	}
+
+	/// Get the visible linked list, without the removed nodes.
+	pub fn list(&self) -> &List<T> {
+		&self.list
+	}
+
+	/// Get the id of a node of the tree, if it belongs to it.
+	pub fn id_of(&self, node: &Node<T>) -> Option<OpId> {
+		self.ids.get(&(Rc::as_ptr(&node.inner) as usize)).copied()
+	}
+
+	/// Get the node with the given id, removed ones included.
+	pub fn node(&self, id: OpId) -> Option<Node<T>> {
+		self.entries.get(&id).map(|entry| entry.node.clone())
+	}
+
+	/// Returns `true` if the node with the given id was removed.
+	pub fn is_removed(&self, id: OpId) -> bool {
+		self.entries.get(&id).is_some_and(|entry| entry.tombstone)
+	}
+
+	/// Get every operation applied so far, local and remote, in the order they were applied.
+	pub fn ops(&self) -> &[CrdtOp<T>] {
+		&self.log
+	}
+
+	/// Inserts a new node under `parent` ( `None` for the root-level ), right after its child `after`
+	/// ( `None` for the first position ), returning its id.
+	///
+	/// Panics if `parent` or `after` are not part of the tree, or if `after` is not a child of `parent`.
+	pub fn insert(&mut self, parent: Option<OpId>, after: Option<OpId>, content: T) -> OpId {
+		if let Some(parent) = parent {
+			assert!(self.entries.contains_key(&parent), "`parent` is not part of the tree");
+		}
+
+		if let Some(after) = after {
+			let entry = self.entries.get(&after).expect("`after` is not part of the tree");
+			assert!(entry.parent == parent, "`after` is not a child of `parent`");
+		}
+
+		self.clock += 1;
+		let id = OpId { counter: self.clock, replica: self.replica };
+
+		self.apply(CrdtOp::Insert { id, parent, after, content });
+		id
+	}
+
+	/// Removes the node with the given id along with its subtree, returning `false`
+	/// if it doesn't exist or was already removed.
+	pub fn remove(&mut self, id: OpId) -> bool {
+		if self.entries.get(&id).is_none_or(|entry| entry.tombstone) {
+			return false;
+		}
+
+		self.apply(CrdtOp::Remove { id });
+		true
+	}
+
+	/// Applies an operation coming from any replica. Operations already applied are ignored,
+	/// operations depending on nodes not received yet are held back until they can apply.
+	pub fn apply(&mut self, op: CrdtOp<T>) {
+		if !self.try_apply(&op) {
+			self.pending.push(op);
+			return;
+		}
+
+		// an applied operation might unlock the held back ones.
+		let mut progress = true;
+
+		while progress {
+			progress = false;
+
+			for op in std::mem::take(&mut self.pending) {
+				if self.try_apply(&op) {
+					progress = true;
+				} else {
+					self.pending.push(op);
+				}
+			}
+		}
+	}
+
+	/// Applies every operation of `other`, converging to the same tree once both replicas merged each other.
+	pub fn merge(&mut self, other: &CrdtTree<T>) {
+		for op in other.log.iter().chain(other.pending.iter()) {
+			self.apply(op.clone());
+		}
+	}
+
+	/// Applies `op` if its dependencies are there, returning `false` otherwise.
+	/// Already applied operations count as applied.
+	fn try_apply(&mut self, op: &CrdtOp<T>) -> bool {
+		match op {
+			CrdtOp::Insert { id, parent, after, content } => {
+				if self.entries.contains_key(id) {
+					return true;
+				}
+
+				if parent.is_some_and(|p| !self.entries.contains_key(&p)) || after.is_some_and(|a| !self.entries.contains_key(&a)) {
+					return false;
+				}
+
+				self.clock = self.clock.max(id.counter);
+				self.integrate(*id, *parent, *after, content.clone());
+			},
+			CrdtOp::Remove { id } => {
+				let entry = match self.entries.get_mut(id) {
+					Some(entry) => entry,
+					None => return false
+				};
+
+				if entry.tombstone {
+					return true;
+				}
+
+				entry.tombstone = true;
+				let node = entry.node.clone();
+
+				// a node under a removed parent isn't in the visible tree, but is still linked to it.
+				unlink_run(&node, &node);
+			}
+		}
+
+		self.log.push(op.clone());
+		true
+	}
+
+	fn siblings(&self, parent: Option<OpId>) -> &Vec<OpId> {
+		match parent {
+			Some(parent) => &self.entries[&parent].children,
+			None => &self.roots
+		}
+	}
+
+	fn integrate(&mut self, id: OpId, parent: Option<OpId>, after: Option<OpId>, content: T) {
+		let siblings = self.siblings(parent);
+
+		let mut index = match after {
+			Some(after) => siblings.iter().position(|s| *s == after).map_or(0, |i| i + 1),
+			None => 0
+		};
+
+		// concurrent insertions at the same position are sorted by descending id.
+		while index < siblings.len() && siblings[index] > id {
+			index += 1;
+		}
+
+		let visible = |s: &OpId| !self.entries[s].tombstone;
+		let prev = siblings[..index].iter().rev().find(|s| visible(s)).map(|s| self.entries[s].node.clone());
+		let next = siblings[index..].iter().find(|s| visible(s)).map(|s| self.entries[s].node.clone());
+
+		let node = Node::new(content);
+
+		match (prev, next, parent) {
+			(Some(prev), _, _) => prev.append_next(node.clone()),
+			(None, Some(next), _) => next.append_prev(node.clone()),
+			(None, None, Some(parent)) => self.entries[&parent].node.append_child(node.clone()),
+			(None, None, None) => {
+				self.list.adopt_roots(&node, &node);
+				self.list.bump_generation();
+				record(Change::Insertion(&node, &node));
+			}
+		}
+
+		match parent {
+			Some(parent) => self.entries.get_mut(&parent).unwrap().children.insert(index, id),
+			None => self.roots.insert(index, id)
+		}
+
+		self.ids.insert(Rc::as_ptr(&node.inner) as usize, id);
+		self.entries.insert(id, Entry {
+			node,
+			parent,
+			tombstone: false,
+			children: Vec::new()
+		});
+	}
+}
@@ -1,16 +1,138 @@
+use std::fmt;
+
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+use crate::path::NodePath;
+
+/// The kind of reference to a `HedelCell`, refer to `HedelError::BorrowConflict`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorrowKind {
+	Shared,
+	Mutable
+}
+
+impl fmt::Display for BorrowKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			BorrowKind::Shared => write!(f, "shared"),
+			BorrowKind::Mutable => write!(f, "mutable")
+		}
+	}
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum HedelError {
-	#[error("There already is a mutable reference alive to `HedelCell`.
-	Getting another mutable reference to it is Undefined Behavior.")]
-	MutBorrow,
-	#[error("There are one or more shared references alive to `HedelCell`.
-	Getting a mutable reference to it is Undefined Behavior.")]
-	MutBorrow_,
-	#[error("There is a mutable reference alive to `HedelCell`.
-	Getting a shared reference to it is Undefined Behavior.")]
-	SharedBorrow,
+	#[error("Can't get a {requested} reference to `HedelCell` while a {held} reference is alive.")]
+	BorrowConflict {
+		/// The reference which was asked for.
+		requested: BorrowKind,
+		/// The reference already alive, which prevented it.
+		held: BorrowKind
+	},
 	#[error("A `NonNull` pointer to the value in HedelCell was null.")]
-	InvalidNonNull
+	InvalidNonNull,
+	#[error("The linked list was structurally modified while iterating over it.")]
+	Invalidated,
+	#[error("The encoded linked list references the node id {0}, which doesn't exist.")]
+	InvalidNodeId(usize),
+	#[error("Failed to encode or decode a linked list: {0}")]
+	Encoding(String),
+	#[error("The boundary points don't define a valid range: {0}")]
+	InvalidRange(&'static str),
+	#[error("Failed to parse the linked list at byte {0}: {1}")]
+	Parse(usize, String),
+	#[error("The path {0} doesn't point to a position in the linked list.")]
+	InvalidPath(String),
+	#[error("A panic occurred while a mutable reference to `HedelCell` was alive,
+	its value might be inconsistent.")]
+	Poisoned,
+	#[error("`{operation}` failed{}: {source}", .path.as_ref().map(|p| format!(" at {}", p)).unwrap_or_default())]
+	Context {
+		/// The step which failed.
+		operation: &'static str,
+		/// The node the step was acting on, if known.
+		path: Option<NodePath>,
+		#[source]
+		source: Box<HedelError>
+	}
+}
+
+/// The variants used before `HedelError::BorrowConflict`, kept as constants so that existing
+/// code comparing or matching against them still compiles.
+///
+/// # Example
+///
+/// ```
+/// #![allow(deprecated)]
+/// use hedel_rs::cell::HedelCell;
+/// use hedel_rs::errors::HedelError;
+///
+/// fn main() {
+///		let cell = HedelCell::new(1);
+///		let _borrow = cell.get();
+///
+///		assert!(matches!(cell.try_get_mut(), Err(HedelError::MutBorrow_)));
+/// }
+/// ```
+#[allow(non_upper_case_globals)]
+impl HedelError {
+	#[deprecated(note = "use `HedelError::BorrowConflict` instead")]
+	pub const MutBorrow: HedelError = HedelError::BorrowConflict { requested: BorrowKind::Mutable, held: BorrowKind::Mutable };
+	#[deprecated(note = "use `HedelError::BorrowConflict` instead")]
+	pub const MutBorrow_: HedelError = HedelError::BorrowConflict { requested: BorrowKind::Mutable, held: BorrowKind::Shared };
+	#[deprecated(note = "use `HedelError::BorrowConflict` instead")]
+	pub const SharedBorrow: HedelError = HedelError::BorrowConflict { requested: BorrowKind::Shared, held: BorrowKind::Mutable };
+}
+
+impl HedelError {
+	/// Wraps the error with the operation which returned it and the path of the node it was acting on,
+	/// so an error coming from deep inside a traversal tells which step failed.
+	/// The original error stays available as the `source`, or through `HedelError::root_cause`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::errors::HedelError;
+	/// use hedel_rs::path::NodePath;
+	///
+	/// fn main() {
+	///		let err = HedelError::Poisoned.context("normalize", Some(NodePath::new(vec![0, 2])));
+	///
+	///		assert_eq!(err.operation(), Some("normalize"));
+	///		assert_eq!(err.path().unwrap().to_string(), "0/2");
+	///		assert!(matches!(err.root_cause(), HedelError::Poisoned));
+	///		assert!(err.to_string().starts_with("`normalize` failed at 0/2: "));
+	/// }
+	/// ```
+	pub fn context(self, operation: &'static str, path: Option<NodePath>) -> Self {
+		HedelError::Context {
+			operation,
+			path,
+			source: Box::new(self)
+		}
+	}
+
+	/// Get the outermost operation the error was wrapped with, if any.
+	pub fn operation(&self) -> Option<&'static str> {
+		match self {
+			HedelError::Context { operation, .. } => Some(operation),
+			_ => None
+		}
+	}
+
+	/// Get the innermost path the error was wrapped with, the closest one to where it occurred.
+	pub fn path(&self) -> Option<&NodePath> {
+		match self {
+			HedelError::Context { path, source, .. } => source.path().or(path.as_ref()),
+			_ => None
+		}
+	}
+
+	/// Get the original error, without any context.
+	pub fn root_cause(&self) -> &HedelError {
+		match self {
+			HedelError::Context { source, .. } => source.root_cause(),
+			other => other
+		}
+	}
 }
@@ -0,0 +1,252 @@
+use std::{
+	fmt::Debug,
+	rc::Rc,
+};
+
+use crate::{
+	node::{
+		Node,
+		GetNode
+	},
+	iter::next_in_subtree,
+};
+
+/// Unfreezes the nodes of a frozen subtree once the last `FrozenNode` into it gets dropped.
+#[derive(Debug)]
+struct FreezeGuard<T: Debug + Clone> {
+	root: Node<T>,
+	/// Every node of the subtree, the root included, as they were when frozen.
+	nodes: Vec<Node<T>>
+}
+
+impl<T: Debug + Clone> Drop for FreezeGuard<T> {
+	fn drop(&mut self) {
+		for node in self.nodes.iter() {
+			match node.inner.try_get() {
+				Ok(inner) => inner.frozen.set(inner.frozen.get() - 1),
+				// don't turn the panic being unwound into an abort.
+				Err(_) if std::thread::panicking() => {},
+				Err(err) => panic!("{}", err)
+			}
+		}
+	}
+}
+
+/// A read-only handle into a frozen subtree, usually built by calling `Node::freeze`.
+///
+/// It only exposes reading and navigation, and never leaves the subtree: the root has no parent nor siblings.
+/// While any handle into the subtree is alive, the structural mutations performed through
+/// its nodes by other handles panic, refer to `Node::freeze`.
+#[derive(Debug, Clone)]
+pub struct FrozenNode<T: Debug + Clone> {
+	guard: Rc<FreezeGuard<T>>,
+	node: Node<T>
+}
+
+impl<T: Debug + Clone> FrozenNode<T> {
+	fn wrap(&self, node: Option<Node<T>>) -> Option<FrozenNode<T>> {
+		Some(FrozenNode {
+			guard: self.guard.clone(),
+			node: node?
+		})
+	}
+
+	/// Returns `true` if `&self` is the root of the frozen subtree.
+	pub fn is_root(&self) -> bool {
+		self.node.ptr_eq(&self.guard.root)
+	}
+
+	/// Calls `f` with a reference to the content, without copying it.
+	pub fn with_content<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+		f(&self.node.get().content)
+	}
+
+	/// Get a copy of the content.
+	pub fn content(&self) -> T {
+		self.node.get().content.clone()
+	}
+
+	/// Get the first child.
+	pub fn child(&self) -> Option<FrozenNode<T>> {
+		self.wrap(self.node.child())
+	}
+
+	/// Get the last child.
+	pub fn get_last_child(&self) -> Option<FrozenNode<T>> {
+		self.wrap(self.node.get_last_child())
+	}
+
+	/// Get the parent, `None` for the root of the frozen subtree.
+	pub fn parent(&self) -> Option<FrozenNode<T>> {
+		if self.is_root() {
+			return None;
+		}
+
+		self.wrap(self.node.parent())
+	}
+
+	/// Get the next sibling, `None` for the root of the frozen subtree.
+	pub fn next(&self) -> Option<FrozenNode<T>> {
+		if self.is_root() {
+			return None;
+		}
+
+		self.wrap(self.node.next())
+	}
+
+	/// Get the previous sibling, `None` for the root of the frozen subtree.
+	pub fn prev(&self) -> Option<FrozenNode<T>> {
+		if self.is_root() {
+			return None;
+		}
+
+		self.wrap(self.node.prev())
+	}
+
+	/// Returns `true` if both handles point to the same `Node`.
+	pub fn ptr_eq(&self, other: &FrozenNode<T>) -> bool {
+		self.node.ptr_eq(&other.node)
+	}
+
+	/// Returns `true` if the handle points to `node`.
+	pub fn is(&self, node: &Node<T>) -> bool {
+		self.node.ptr_eq(node)
+	}
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Freezes the subtree of `&self` and returns a read-only handle to it, which can be handed
+	/// to a subsystem that must not corrupt the tree.
+	///
+	/// Until every `FrozenNode` into the subtree gets dropped, the structural mutations through its nodes
+	/// panic: adding, removing or moving nodes inside it, and moving `&self` itself.
+	/// The subtree can still be moved around along with an ancestor, and its contents can still be changed.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use std::panic::{
+	///		catch_unwind,
+	///		AssertUnwindSafe
+	/// };
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(3)), node!(4));
+	///		let frozen = node.freeze();
+	///
+	///		let two = frozen.child().unwrap();
+	///		assert_eq!(two.child().unwrap().content(), 3);
+	///		assert_eq!(two.with_content(|c| c * 10), 20);
+	///		assert!(frozen.parent().is_none());
+	///		assert!(node.is_frozen());
+	///
+	///		let attempt = catch_unwind(AssertUnwindSafe(|| node.child().unwrap().append_child(node!(5))));
+	///		assert!(attempt.is_err());
+	///
+	///		let leaf = node.get_last_child().unwrap();
+	///		let attempt = catch_unwind(AssertUnwindSafe(|| leaf.insert_child(0, node!(6))));
+	///		assert!(attempt.is_err());
+	///		assert!(leaf.child().is_none());
+	///
+	///		drop(frozen);
+	///		drop(two);
+	///
+	///		node.child().unwrap().append_child(node!(5));
+	///		assert!(!node.is_frozen());
+	/// }
+	/// ```
+	///
+	/// The nodes moved or unlinked by a mutation are checked as well, not only the node it's performed through:
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use std::panic::{
+	///		catch_unwind,
+	///		AssertUnwindSafe
+	/// };
+	///
+	/// fn main() {
+	///		let parent = node!(0, node!(1), node!(2, node!(3)), node!(4));
+	///		let two = parent.child().unwrap().next().unwrap();
+	///		let frozen = two.freeze();
+	///
+	///		// moving the children of `parent` would move `two`.
+	///		assert!(catch_unwind(AssertUnwindSafe(|| parent.take_children())).is_err());
+	///		assert!(catch_unwind(AssertUnwindSafe(|| parent.replace_children(vec![node!(5)]))).is_err());
+	///		assert!(catch_unwind(AssertUnwindSafe(|| parent.partition_children(&NodeCollection::new()))).is_err());
+	///
+	///		// `two` is in the middle of the run.
+	///		let first = parent.child().unwrap();
+	///		let last = parent.get_last_child().unwrap();
+	///		assert!(catch_unwind(AssertUnwindSafe(|| Node::detach_range(&first, &last))).is_err());
+	///
+	///		// `two` is among the incoming nodes.
+	///		let other = node!(6);
+	///		assert!(catch_unwind(AssertUnwindSafe(|| other.append_children(vec![node!(7), two.clone()]))).is_err());
+	///		assert!(other.child().is_none());
+	///
+	///		assert!(parent.child().unwrap().next().unwrap().ptr_eq(&two));
+	///		assert_eq!(two.child().unwrap().to_content(), 3);
+	///		assert!(two.parent().unwrap().ptr_eq(&parent));
+	///
+	///		drop(frozen);
+	///		assert_eq!(parent.take_children().root_count(), 3);
+	/// }
+	/// ```
+	pub fn freeze(&self) -> FrozenNode<T> {
+		let mut nodes = Vec::new();
+		let mut next = Some(self.clone());
+
+		// nodes can't join or leave the subtree while it's frozen, so every node gets marked once.
+		while let Some(node) = next {
+			{
+				let frozen = &node.get().frozen;
+				frozen.set(frozen.get() + 1);
+			}
+
+			next = next_in_subtree(&node, self);
+			nodes.push(node);
+		}
+
+		FrozenNode {
+			guard: Rc::new(FreezeGuard { root: self.clone(), nodes }),
+			node: self.clone()
+		}
+	}
+
+	/// Returns `true` if `&self` belongs to a frozen subtree.
+	pub fn is_frozen(&self) -> bool {
+		self.get().frozen.get() > 0
+	}
+
+	/// Panics if `&self` belongs to a frozen subtree. Called by every structural mutation,
+	/// on the node it's performed through and on every node it moves or unlinks, before changing anything.
+	pub(crate) fn check_mutable(&self) {
+		assert!(!self.is_frozen(), "attempted to mutate a frozen subtree, refer to `Node::freeze`");
+	}
+
+	/// Calls `Node::check_mutable` on `&self` and on every one of its descendants.
+	pub(crate) fn check_subtree_mutable(&self) {
+		let mut next = Some(self.clone());
+
+		while let Some(node) = next {
+			node.check_mutable();
+			next = next_in_subtree(&node, self);
+		}
+	}
+
+	/// Calls `Node::check_mutable` on `&self` and on every one of its children.
+	pub(crate) fn check_children_mutable(&self) {
+		self.check_mutable();
+
+		let mut child = self.child();
+
+		while let Some(c) = child {
+			c.check_mutable();
+			child = c.next();
+		}
+	}
+}
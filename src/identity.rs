@@ -0,0 +1,273 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+	rc::{
+		Rc,
+		Weak
+	},
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+	Serialize,
+	Deserialize
+};
+
+use crate::{
+	node::{
+		Node,
+		WeakNode
+	},
+	list::List,
+	iter::next_in_subtree,
+	errors::HedelError,
+};
+
+/// A `Node` flattened into an `IdentityList`, pointing to the other nodes by id.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IdentityNode<T> {
+	pub id: usize,
+	pub content: T,
+	pub parent: Option<usize>,
+	pub prev: Option<usize>,
+	pub next: Option<usize>,
+	pub child: Option<usize>
+}
+
+/// A linked list encoded by references: every node gets an id ( its position in document order )
+/// and the pointers between nodes are stored as ids.
+///
+/// Unlike a nested encoding, this preserves node identity across a round-trip:
+/// the application records the id of every `WeakNode` it holds with `NodeIds::id_of`
+/// before serializing, and re-binds its handles by id after `IdentityList::into_list`.
+/// With the `serde` feature it implements `Serialize` and `Deserialize`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::identity::IdentityList;
+///
+///	let list = list!(node!(1, node!(2)));
+///	let (encoded, _) = list.to_identity();
+///
+///	let json = serde_json::to_string(&encoded).unwrap();
+///	let decoded: IdentityList<i32> = serde_json::from_str(&json).unwrap();
+///	assert_eq!(decoded, encoded);
+/// # }
+/// ```
+///
+/// Re-binding handles:
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let list = list!(node!(1, node!(2)), node!(3));
+///		let handle = list.first().unwrap().child().unwrap().downgrade();
+///
+///		let (encoded, ids) = list.to_identity();
+///		let id = ids.id_of(&handle).unwrap();
+///
+///		// ... serialize `encoded` and `id`, deserialize them later ...
+///
+///		let (decoded, nodes) = encoded.into_list().unwrap();
+///		let handle = nodes[id].downgrade();
+///
+///		assert_eq!(handle.upgrade().unwrap().to_content(), 2);
+///		assert_eq!(decoded.first().unwrap().get_last_sibling().unwrap().to_content(), 3);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IdentityList<T> {
+	/// Id of the first root-level node, if any.
+	pub first: Option<usize>,
+	/// The nodes, indexed by id.
+	pub nodes: Vec<IdentityNode<T>>
+}
+
+/// Maps the nodes of a linked list to the ids assigned by `List::to_identity`.
+#[derive(Debug, Clone, Default)]
+pub struct NodeIds {
+	ids: HashMap<usize, usize>
+}
+
+impl NodeIds {
+	/// Get the id assigned to the node, if it was part of the encoded linked list.
+	pub fn id_of<T: Debug + Clone>(&self, node: &WeakNode<T>) -> Option<usize> {
+		self.ids.get(&(Weak::as_ptr(&node.inner) as usize)).copied()
+	}
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Encodes the linked list by references, refer to `IdentityList`.
+	pub fn to_identity(&self) -> (IdentityList<T>, NodeIds) {
+		let mut order = Vec::new();
+		let mut ids = NodeIds::default();
+		let mut root = self.first();
+
+		while let Some(r) = root {
+			let mut next = Some(r.clone());
+
+			while let Some(node) = next {
+				next = next_in_subtree(&node, &r);
+				ids.ids.insert(Rc::as_ptr(&node.inner) as usize, order.len());
+				order.push(node);
+			}
+
+			root = r.next();
+		}
+
+		let id = |node: Option<Node<T>>| node.and_then(|n| ids.ids.get(&(Rc::as_ptr(&n.inner) as usize)).copied());
+
+		let nodes = order.iter().enumerate().map(|(i, node)| IdentityNode {
+			id: i,
+			content: node.get().content.clone(),
+			parent: id(node.parent()),
+			prev: id(node.prev()),
+			next: id(node.next()),
+			child: id(node.child())
+		}).collect();
+
+		let encoded = IdentityList {
+			first: if order.is_empty() { None } else { Some(0) },
+			nodes
+		};
+
+		(encoded, ids)
+	}
+}
+
+/// The `parent`, `prev`, `next` and `child` ids of a node.
+type Links = (Option<usize>, Option<usize>, Option<usize>, Option<usize>);
+
+/// Checks that the links describe a linked list starting from `first`, refer to `IdentityList::into_list`.
+/// The ids are expected to be in bounds.
+fn validate(first: Option<usize>, links: &[Links]) -> Result<(), HedelError> {
+	let invalid = |id: usize, reason: &str| Err(HedelError::Encoding(format!("node {}: {}", id, reason)));
+	// the number of `next` and `child` pointers to every node, the first one counting as pointed by the list.
+	let mut incoming = vec![0; links.len()];
+
+	if let Some(first) = first {
+		incoming[first] += 1;
+
+		if links[first].0.is_some() || links[first].1.is_some() {
+			return invalid(first, "the first node has a parent or a previous node");
+		}
+	}
+
+	for (id, (parent, prev, next, child)) in links.iter().enumerate() {
+		if let Some(next) = *next {
+			incoming[next] += 1;
+
+			if links[next].1 != Some(id) || links[next].0 != *parent {
+				return invalid(id, "the next node doesn't point back to it, or has another parent");
+			}
+		}
+
+		if let Some(child) = *child {
+			incoming[child] += 1;
+
+			if links[child].0 != Some(id) || links[child].1.is_some() {
+				return invalid(id, "the first child doesn't point back to it, or has a previous node");
+			}
+		}
+
+		if let Some(prev) = *prev {
+			if links[prev].2 != Some(id) {
+				return invalid(id, "the previous node doesn't point to it as the next one");
+			}
+		} else if let Some(parent) = *parent {
+			if links[parent].3 != Some(id) {
+				return invalid(id, "the parent doesn't point to it as the first child");
+			}
+		}
+	}
+
+	if let Some(id) = incoming.iter().position(|count| *count != 1) {
+		return invalid(id, "it has to be the next or the first child of exactly one node");
+	}
+
+	// every node is pointed once, so a cycle can't be reached from the first node, only left out of the walk.
+	let mut reached = 0;
+	let mut stack: Vec<usize> = first.into_iter().collect();
+
+	while let Some(id) = stack.pop() {
+		reached += 1;
+		stack.extend(links[id].2);
+		stack.extend(links[id].3);
+	}
+
+	if reached != links.len() {
+		return Err(HedelError::Encoding("some nodes can't be reached from the first one".to_string()));
+	}
+
+	Ok(())
+}
+
+impl<T: Debug + Clone> IdentityList<T> {
+	/// Rebuilds the linked list, returning it along with all its nodes indexed by id.
+	/// Returns `HedelError::InvalidNodeId` if a node points to an id which doesn't exist,
+	/// and `HedelError::Encoding` if the pointers don't describe a linked list: every node but the first
+	/// has to be the `next` of exactly one node or the `child` of exactly one node, the pointers have to agree
+	/// in both directions, and every node has to be reached from the first one.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let (mut encoded, _) = list!(node!(1, node!(2))).to_identity();
+	///		assert!(encoded.clone().into_list().is_ok());
+	///
+	///		// the child points to itself as the next sibling.
+	///		encoded.nodes[1].next = Some(1);
+	///		encoded.nodes[1].prev = Some(1);
+	///		assert!(encoded.into_list().is_err());
+	/// }
+	/// ```
+	pub fn into_list(self) -> Result<(List<T>, Vec<Node<T>>), HedelError> {
+		let len = self.nodes.len();
+		let check = |id: Option<usize>| match id {
+			Some(id) if id >= len => Err(HedelError::InvalidNodeId(id)),
+			_ => Ok(id)
+		};
+
+		let mut links = Vec::with_capacity(len);
+		let mut nodes = Vec::with_capacity(len);
+
+		for (i, flat) in self.nodes.into_iter().enumerate() {
+			if flat.id != i {
+				return Err(HedelError::InvalidNodeId(flat.id));
+			}
+
+			links.push((check(flat.parent)?, check(flat.prev)?, check(flat.next)?, check(flat.child)?));
+			nodes.push(Node::new(flat.content));
+		}
+
+		let first = check(self.first)?;
+		validate(first, &links)?;
+
+		for (node, (parent, prev, next, child)) in nodes.iter().zip(links) {
+			let mut borrow = node.get_mut();
+			borrow.parent = parent.map(|id| nodes[id].downgrade());
+			borrow.prev = prev.map(|id| nodes[id].downgrade());
+			borrow.next = next.map(|id| nodes[id].clone());
+			borrow.child = child.map(|id| nodes[id].clone());
+		}
+
+		let list = match first {
+			Some(first) => List::new(nodes[first].clone()),
+			None => List::empty()
+		};
+
+		Ok((list, nodes))
+	}
+}
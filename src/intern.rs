@@ -0,0 +1,197 @@
+use std::{
+	any::Any,
+	borrow::Borrow,
+	collections::HashSet,
+	fmt::{
+		self,
+		Debug,
+		Display
+	},
+	hash::{
+		Hash,
+		Hasher
+	},
+	ops::Deref,
+	rc::Rc,
+};
+
+use crate::{
+	list::List,
+	iter::next_in_subtree,
+};
+
+/// A shared, immutable content: cloning it only bumps a reference counter.
+/// Built by `List::intern`, equal values interned by the same `List` share their storage,
+/// which suits trees with many repeated payloads such as tag names or enum-like labels.
+///
+/// Requires the `intern` feature.
+pub struct Interned<S: ?Sized>(Rc<S>);
+
+impl<S: ?Sized> Interned<S> {
+	/// Wraps `value` without going through an interner, it doesn't share its storage with any other value.
+	pub fn new<V: Into<Rc<S>>>(value: V) -> Self {
+		Self(value.into())
+	}
+
+	/// Returns `true` if both values share the same storage.
+	pub fn ptr_eq(&self, other: &Interned<S>) -> bool {
+		Rc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+impl<S: ?Sized> Clone for Interned<S> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<S: ?Sized> Deref for Interned<S> {
+	type Target = S;
+
+	fn deref(&self) -> &S {
+		&self.0
+	}
+}
+
+impl<S: ?Sized> Borrow<S> for Interned<S> {
+	fn borrow(&self) -> &S {
+		&self.0
+	}
+}
+
+impl<S: ?Sized> AsRef<S> for Interned<S> {
+	fn as_ref(&self) -> &S {
+		&self.0
+	}
+}
+
+impl<S: ?Sized> From<Interned<S>> for Rc<S> {
+	fn from(interned: Interned<S>) -> Self {
+		interned.0
+	}
+}
+
+impl<S: ?Sized + PartialEq> PartialEq for Interned<S> {
+	fn eq(&self, other: &Self) -> bool {
+		self.ptr_eq(other) || *self.0 == *other.0
+	}
+}
+
+impl<S: ?Sized + Eq> Eq for Interned<S> {}
+
+impl<S: ?Sized + Hash> Hash for Interned<S> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.hash(state)
+	}
+}
+
+impl<S: ?Sized + Debug> Debug for Interned<S> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<S: ?Sized + Display> Display for Interned<S> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+/// The pool of a `List`, type-erased as the `List` isn't generic over the interned type.
+#[derive(Default)]
+pub(crate) struct Interner {
+	pool: Option<Box<dyn Any>>
+}
+
+impl Debug for Interner {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("Interner")
+	}
+}
+
+impl Interner {
+	fn pool<S: ?Sized + Eq + Hash + 'static>(&mut self) -> &mut HashSet<Interned<S>> {
+		self.pool
+			.get_or_insert_with(|| Box::new(HashSet::<Interned<S>>::new()))
+			.downcast_mut()
+			.unwrap()
+	}
+}
+
+impl<S: ?Sized + Eq + Hash + Debug + 'static> List<Interned<S>> {
+	/// Get the shared copy of `value` from the interner of the linked list, storing it first if it's not there.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::intern::Interned;
+	///
+	/// fn main() {
+	///		let list: List<Interned<str>> = List::new(Node::new(Interned::new("ul")));
+	///		let ul = list.first().unwrap();
+	///		ul.append_child(Node::new(list.intern("li")));
+	///		ul.append_child(Node::new(list.intern(String::from("li"))));
+	///
+	///		let first = ul.child().unwrap().to_content();
+	///		let last = ul.get_last_child().unwrap().to_content();
+	///		assert!(first.ptr_eq(&last));
+	///		assert_eq!(&*first, "li");
+	///
+	///		// nodes built without the interner don't share their storage until `List::reintern`.
+	///		ul.append_child(Node::new(Interned::new("li")));
+	///		assert_eq!(list.reintern(), 1);
+	///		assert_eq!(list.interned_len(), 2);
+	/// }
+	/// ```
+	pub fn intern<V: AsRef<S> + Into<Rc<S>>>(&self, value: V) -> Interned<S> {
+		let mut interner = self.interner.get_mut();
+		let pool = interner.pool::<S>();
+
+		if let Some(interned) = pool.get(value.as_ref()) {
+			return interned.clone();
+		}
+
+		let interned = Interned::new(value);
+		pool.insert(interned.clone());
+		interned
+	}
+
+	/// Returns the number of distinct values in the interner.
+	pub fn interned_len(&self) -> usize {
+		self.interner.get_mut().pool::<S>().len()
+	}
+
+	/// Drops the values of the interner which aren't used by any content anymore.
+	pub fn shrink_interner(&self) {
+		self.interner.get_mut().pool::<S>().retain(|interned| Rc::strong_count(&interned.0) > 1);
+	}
+
+	/// Replaces every content of the linked list with its shared copy from the interner,
+	/// e.g after attaching nodes built with `Interned::new`. Returns the number of replaced contents.
+	pub fn reintern(&self) -> usize {
+		let mut replaced = 0;
+		let mut root = self.first();
+
+		while let Some(r) = root {
+			let mut next = Some(r.clone());
+
+			while let Some(node) = next {
+				next = next_in_subtree(&node, &r);
+
+				let content = node.get().content.clone();
+				let shared = self.intern(content.clone());
+
+				if !shared.ptr_eq(&content) {
+					node.get_mut().content = shared;
+					replaced += 1;
+				}
+			}
+
+			root = r.next();
+		}
+
+		replaced
+	}
+}
@@ -0,0 +1,1207 @@
+use std::{
+	collections::VecDeque,
+	fmt::Debug,
+	iter::{
+		FusedIterator,
+		Map
+	},
+};
+
+use crate::{
+	node::{
+		Node,
+		WeakNode,
+		GetNode,
+		DetachNode
+	},
+	list::List,
+	errors::HedelError,
+	oplog::{
+		record,
+		Change
+	},
+};
+
+/// Iterator over overlapping groups of `size` consecutive siblings.
+///
+/// Usually built by calling `IterNode::sibling_windows`.
+pub struct SiblingWindows<T: Debug + Clone> {
+	next: Option<Node<T>>,
+	window: VecDeque<Node<T>>,
+	size: usize
+}
+
+impl<T: Debug + Clone> Iterator for SiblingWindows<T> {
+	type Item = Vec<Node<T>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if !self.window.is_empty() {
+			self.window.pop_front();
+		}
+
+		while self.window.len() < self.size {
+			let node = self.next.take()?;
+			self.next = node.next();
+			self.window.push_back(node);
+		}
+
+		Some(self.window.iter().cloned().collect())
+	}
+}
+
+/// Iterator over non-overlapping groups of at most `size` consecutive siblings.
+/// The last group may be shorter.
+///
+/// Usually built by calling `IterNode::sibling_chunks`.
+pub struct SiblingChunks<T: Debug + Clone> {
+	next: Option<Node<T>>,
+	size: usize
+}
+
+impl<T: Debug + Clone> Iterator for SiblingChunks<T> {
+	type Item = Vec<Node<T>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut chunk = Vec::with_capacity(self.size);
+
+		while chunk.len() < self.size {
+			match self.next.take() {
+				Some(node) => {
+					self.next = node.next();
+					chunk.push(node);
+				},
+				None => break
+			}
+		}
+
+		if chunk.is_empty() {
+			return None;
+		}
+
+		Some(chunk)
+	}
+}
+
+/// Steps to the `Node` after `node` in document order, without leaving the subtree of `root`.
+pub(crate) fn next_in_subtree<T: Debug + Clone>(node: &Node<T>, root: &Node<T>) -> Option<Node<T>> {
+	if let Some(child) = node.child() {
+		return Some(child);
+	}
+
+	skip_in_subtree(node, root)
+}
+
+/// Steps to the `Node` after the descendants of `node` in document order, without leaving the subtree of `root`.
+pub(crate) fn skip_in_subtree<T: Debug + Clone>(node: &Node<T>, root: &Node<T>) -> Option<Node<T>> {
+	let mut current = node.clone();
+
+	while !current.ptr_eq(root) {
+		if let Some(next) = current.next() {
+			return Some(next);
+		}
+
+		current = current.parent()?;
+	}
+
+	None
+}
+
+/// Lazy iterator over the descendants of a node in document order ( pre-order ).
+/// Whole branches can be pruned mid-iteration with `Descendants::skip_subtree`.
+///
+/// Usually built by calling `IterNode::descendants`.
+pub struct Descendants<T: Debug + Clone> {
+	root: Node<T>,
+	next: Option<Node<T>>,
+	/// The node yielded last along with its depth, the one `Descendants::skip_subtree` applies to.
+	last: Option<(Node<T>, usize)>
+}
+
+impl<T: Debug + Clone> Descendants<T> {
+	/// Skips the descendants of the node yielded last, the iteration continues after its subtree.
+	/// Does nothing before the first call to `next`.
+	pub fn skip_subtree(&mut self) {
+		if let Some((last, _)) = self.last.as_ref() {
+			self.next = skip_in_subtree(last, &self.root);
+		}
+	}
+
+	/// Get the depth of the node yielded last, relative to the root of the iteration: its children are at depth `1`.
+	/// Returns `0` before the first call to `next`. Depths are tracked while walking, without climbing up to the root for every node.
+	pub fn depth(&self) -> usize {
+		self.last.as_ref().map_or(0, |(_, depth)| *depth)
+	}
+
+	/// Yields every node along with its depth, refer to `Descendants::depth`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("ul", node!("li", node!("ul", node!("li"))), node!("li"));
+	///		let mut out = String::new();
+	///
+	///		for (n, depth) in node.descendants().with_depth() {
+	///			out.push_str(&format!("{}{}\n", "  ".repeat(depth - 1), n.to_content()));
+	///		}
+	///
+	///		assert_eq!(out, "li\n  ul\n    li\nli\n");
+	/// }
+	/// ```
+	pub fn with_depth(self) -> WithDepth<T> {
+		WithDepth {
+			inner: self
+		}
+	}
+}
+
+impl<T: Debug + Clone> Iterator for Descendants<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+
+		let depth = match self.last.take() {
+			Some((last, depth)) => depth_after(&last, depth, &node),
+			None => 1
+		};
+
+		self.next = next_in_subtree(&node, &self.root);
+		self.last = Some((node.clone(), depth));
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for Descendants<T> {}
+
+/// Get the depth of `next`, the node following `node` in document order, given the depth of `node`.
+fn depth_after<T: Debug + Clone>(node: &Node<T>, depth: usize, next: &Node<T>) -> usize {
+	if node.child().is_some_and(|c| c.ptr_eq(next)) {
+		return depth + 1;
+	}
+
+	// climbs up until `next` is the next sibling.
+	let mut depth = depth;
+	let mut current = node.clone();
+
+	while !current.next().is_some_and(|n| n.ptr_eq(next)) {
+		current = match current.parent() {
+			Some(parent) => parent,
+			None => break
+		};
+
+		depth -= 1;
+	}
+
+	depth
+}
+
+/// Iterator over the descendants of a node along with their depth, in document order.
+///
+/// Usually built by calling `Descendants::with_depth`.
+pub struct WithDepth<T: Debug + Clone> {
+	inner: Descendants<T>
+}
+
+impl<T: Debug + Clone> WithDepth<T> {
+	/// Same as `Descendants::skip_subtree`.
+	pub fn skip_subtree(&mut self) {
+		self.inner.skip_subtree();
+	}
+}
+
+impl<T: Debug + Clone> Iterator for WithDepth<T> {
+	type Item = (Node<T>, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.inner.next()?;
+		Some((node, self.inner.depth()))
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for WithDepth<T> {}
+
+/// `WithDepth` yielding `(depth, node)` pairs instead, refer to `IterNode::descendants_with_depth`.
+pub type DescendantsWithDepth<T> = Map<WithDepth<T>, fn((Node<T>, usize)) -> (usize, Node<T>)>;
+
+/// Iterator over the descendants without children of a node, in document order.
+///
+/// Usually built by calling `IterNode::leaves`.
+pub struct Leaves<T: Debug + Clone> {
+	root: Node<T>,
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Leaves<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let leaf = self.next.take()?;
+
+		self.next = next_in_subtree(&leaf, &self.root)
+			.map(|n| n.first_leaf().unwrap_or(n));
+
+		Some(leaf)
+	}
+}
+
+/// Iterator over the nodes captured by `IterNode::snapshot`, skipping the ones
+/// dropped in the meantime.
+pub struct Snapshot<T: Debug + Clone> {
+	nodes: std::vec::IntoIter<WeakNode<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Snapshot<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for weak in self.nodes.by_ref() {
+			if let Some(node) = weak.upgrade() {
+				return Some(node);
+			}
+		}
+
+		None
+	}
+}
+
+/// Iterator over a subtree in breadth-first order, yielding every `Node` along with its depth
+/// relative to the subtree root.
+///
+/// Usually built by calling `IterNode::levels`.
+pub struct Levels<T: Debug + Clone> {
+	queue: VecDeque<(usize, Node<T>)>
+}
+
+impl<T: Debug + Clone> Iterator for Levels<T> {
+	type Item = (usize, Node<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (depth, node) = self.queue.pop_front()?;
+		let mut child = node.child();
+
+		while let Some(c) = child {
+			child = c.next();
+			self.queue.push_back((depth + 1, c));
+		}
+
+		Some((depth, node))
+	}
+}
+
+/// Iterator over a subtree in breadth-first order, yielding every `Node` of a level before moving to the next one.
+///
+/// Usually built by calling `IterNode::breadth_first`.
+pub struct BreadthFirst<T: Debug + Clone> {
+	inner: Levels<T>,
+	depth: usize
+}
+
+impl<T: Debug + Clone> BreadthFirst<T> {
+	/// Get the depth of the node yielded last, relative to the subtree root which is at depth `0`.
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
+
+	/// Yields every node along with its depth, same as `IterNode::levels`.
+	pub fn with_depth(self) -> Levels<T> {
+		self.inner
+	}
+}
+
+impl<T: Debug + Clone> Iterator for BreadthFirst<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (depth, node) = self.inner.next()?;
+		self.depth = depth;
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for BreadthFirst<T> {}
+
+/// Iterator over the direct children of a node, in order.
+///
+/// Usually built by calling `IterNode::children`.
+pub struct Children<T: Debug + Clone> {
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Children<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		self.next = node.next();
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for Children<T> {}
+
+/// Iterator over the siblings of a node in document order, from the first one to the last one.
+///
+/// Usually built by calling `IterNode::siblings` or `IterNode::siblings_and_self`.
+pub struct Siblings<T: Debug + Clone> {
+	next: Option<Node<T>>,
+	/// The node left out, if any.
+	skip: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Siblings<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut node = self.next.take()?;
+
+		if self.skip.as_ref().is_some_and(|skip| skip.ptr_eq(&node)) {
+			node = node.next()?;
+		}
+
+		self.next = node.next();
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for Siblings<T> {}
+
+/// Iterator over the siblings coming after a node, following the `next` pointers.
+///
+/// Usually built by calling `IterNode::following_siblings`.
+pub struct FollowingSiblings<T: Debug + Clone> {
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for FollowingSiblings<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		self.next = node.next();
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for FollowingSiblings<T> {}
+
+/// Iterator over the siblings coming before a node, following the `prev` pointers.
+///
+/// Usually built by calling `IterNode::preceding_siblings`.
+pub struct PrecedingSiblings<T: Debug + Clone> {
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for PrecedingSiblings<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		self.next = node.prev();
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for PrecedingSiblings<T> {}
+
+/// Consuming iterator over the contents of a subtree, or of a whole `List`, in document order.
+/// Every node gets unlinked from the others right before its content is yielded, so the nodes
+/// already visited are released as the iteration goes, unless another handle keeps them alive.
+/// The content is moved out of the nodes without any other handle, and cloned from the others.
+///
+/// Built by calling `into_iter` on a `Node` or on a `List`, which panics if any node to consume
+/// belongs to a frozen subtree, refer to `Node::freeze`.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use std::panic::{
+///		catch_unwind,
+///		AssertUnwindSafe
+/// };
+///
+/// fn main() {
+///		let list = list!(node!(1, node!(2, node!(3)), node!(4)), node!(5));
+///		let two = list.first().unwrap().child().unwrap();
+///
+///		let contents: Vec<i32> = two.into_iter().collect();
+///		assert_eq!(contents, vec![2, 3]);
+///		assert_eq!(list.first().unwrap().child().unwrap().to_content(), 4);
+///
+///		let rest: Vec<i32> = list.clone().into_iter().collect();
+///		assert_eq!(rest, vec![1, 4, 5]);
+///		assert!(list.first().is_none());
+///
+///		let mut names = Vec::new();
+///
+///		for name in node!(String::from("a"), node!(String::from("b"))) {
+///			names.push(name);
+///		}
+///
+///		assert_eq!(names, vec!["a", "b"]);
+///
+///		let node = node!(1, node!(2, node!(3)));
+///		let frozen = node.child().unwrap().freeze();
+///
+///		assert!(catch_unwind(AssertUnwindSafe(|| node.clone().into_iter().count())).is_err());
+///		assert!(catch_unwind(AssertUnwindSafe(|| List::new(node.clone()).into_iter().count())).is_err());
+///		assert_eq!(frozen.child().unwrap().content(), 3);
+/// }
+/// ```
+pub struct IntoContents<T: Debug + Clone> {
+	/// The nodes to visit, the next one last.
+	stack: Vec<Node<T>>
+}
+
+impl<T: Debug + Clone> IntoContents<T> {
+	/// Unlinks the run of siblings starting from `first` and pushes it on the stack.
+	fn push_run(&mut self, first: Option<Node<T>>) {
+		let start = self.stack.len();
+		let mut next = first;
+
+		while let Some(node) = next {
+			{
+				let mut borrow = node.get_mut();
+				borrow.parent = None;
+				borrow.prev = None;
+				borrow.list = None;
+				next = borrow.next.take();
+			}
+
+			self.stack.push(node);
+		}
+
+		self.stack[start..].reverse();
+	}
+}
+
+impl<T: Debug + Clone> Iterator for IntoContents<T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.stack.pop()?;
+		let child = node.get_mut().child.take();
+		self.push_run(child);
+
+		match std::rc::Rc::try_unwrap(node.inner) {
+			Ok(cell) => Some(cell.into_inner().content),
+			Err(inner) => Some(inner.get().content.clone())
+		}
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for IntoContents<T> {}
+
+/// Detaches the node and consumes its subtree, refer to `IntoContents`.
+impl<T: Debug + Clone> IntoIterator for Node<T> {
+	type Item = T;
+	type IntoIter = IntoContents<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.check_subtree_mutable();
+		self.detach();
+
+		IntoContents {
+			stack: vec![self]
+		}
+	}
+}
+
+/// Empties the linked list and consumes every root-level node along with its subtree, refer to `IntoContents`.
+/// The other handles to the same `List` are left empty.
+impl<T: Debug + Clone> IntoIterator for List<T> {
+	type Item = T;
+	type IntoIter = IntoContents<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let mut iter = IntoContents {
+			stack: Vec::new()
+		};
+
+		if let (Some(first), Some(last)) = (self.first(), self.last()) {
+			for root in std::iter::successors(Some(first.clone()), |node| node.next()) {
+				root.check_subtree_mutable();
+			}
+
+			record(Change::Removal(&first, &last));
+			self.take_roots();
+			self.bump_generation();
+
+			iter.push_run(Some(first));
+		}
+
+		iter
+	}
+}
+
+/// Iterator over the ancestors of a node, from its parent up to the root-level node.
+///
+/// Usually built by calling `IterNode::ancestors`.
+pub struct Ancestors<T: Debug + Clone> {
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Ancestors<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		self.next = node.parent();
+		Some(node)
+	}
+}
+
+impl<T: Debug + Clone> FusedIterator for Ancestors<T> {}
+
+/// Iterator over every `Node` coming after a node in document order.
+///
+/// Usually built by calling `IterNode::following`.
+pub struct Following<T: Debug + Clone> {
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Following<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		self.next = node.next_in_document();
+		Some(node)
+	}
+}
+
+/// Iterator over every `Node` coming before a node in document order, walking backwards.
+///
+/// Usually built by calling `IterNode::preceding`.
+pub struct Preceding<T: Debug + Clone> {
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Preceding<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		self.next = node.prev_in_document();
+		Some(node)
+	}
+}
+
+/// An event emitted while streaming a subtree in document order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeEvent<T> {
+	/// A node was entered, carries a copy of its content.
+	Start(T),
+	/// The last entered node which wasn't closed yet was left.
+	End
+}
+
+/// Iterator over the `NodeEvent`s of a subtree.
+///
+/// Usually built by calling `IterNode::events`.
+pub struct Events<T: Debug + Clone> {
+	next: Option<Node<T>>,
+	open: Vec<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Events<T> {
+	type Item = NodeEvent<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(node) = self.next.take() {
+			self.next = node.child();
+			let content = node.get().content.clone();
+			self.open.push(node);
+			return Some(NodeEvent::Start(content));
+		}
+
+		let closed = self.open.pop()?;
+
+		// the subtree root is closed last, its siblings are not part of the stream.
+		if !self.open.is_empty() {
+			self.next = closed.next();
+		}
+
+		Some(NodeEvent::End)
+	}
+}
+
+/// Wraps an iterator walking a linked list and checks the `List` generation before yielding
+/// every item. If the linked list was structurally modified since the iterator was built,
+/// it yields `HedelError::Invalidated` once and then stops.
+///
+/// Usually built by calling `CheckedIter::checked`.
+pub struct Checked<I, T: Debug + Clone> {
+	iter: I,
+	list: List<T>,
+	generation: u64,
+	invalidated: bool
+}
+
+impl<I: Iterator, T: Debug + Clone> Iterator for Checked<I, T> {
+	type Item = Result<I::Item, HedelError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.invalidated {
+			return None;
+		}
+
+		if self.list.generation() != self.generation {
+			self.invalidated = true;
+			return Some(Err(HedelError::Invalidated));
+		}
+
+		self.iter.next().map(Ok)
+	}
+}
+
+pub trait CheckedIter: Iterator + Sized {
+	/// Makes the iterator check the generation of `list` before yielding every item.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::errors::HedelError;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2), node!(3)));
+	///		let first = list.first().unwrap();
+	///		let mut events = first.events().checked(&list);
+	///
+	///		assert!(events.next().unwrap().is_ok());
+	///
+	///		first.append_child(node!(4));
+	///		assert!(matches!(events.next(), Some(Err(HedelError::Invalidated))));
+	///		assert!(events.next().is_none());
+	/// }
+	/// ```
+	fn checked<T: Debug + Clone>(self, list: &List<T>) -> Checked<Self, T> {
+		Checked {
+			iter: self,
+			list: list.clone(),
+			generation: list.generation(),
+			invalidated: false
+		}
+	}
+}
+
+impl<I: Iterator> CheckedIter for I {}
+
+pub trait IterNode<T: Debug + Clone> {
+	fn sibling_windows(&self, size: usize) -> SiblingWindows<T>;
+	fn sibling_chunks(&self, size: usize) -> SiblingChunks<T>;
+	fn events(&self) -> Events<T>;
+	fn following(&self) -> Following<T>;
+	fn preceding(&self) -> Preceding<T>;
+	fn leaves(&self) -> Leaves<T>;
+	fn descendants(&self) -> Descendants<T>;
+	fn descendants_with_depth(&self) -> DescendantsWithDepth<T>;
+	fn levels(&self) -> Levels<T>;
+	fn breadth_first(&self) -> BreadthFirst<T>;
+	fn ancestors(&self) -> Ancestors<T>;
+	fn children(&self) -> Children<T>;
+	fn siblings(&self) -> Siblings<T>;
+	fn siblings_and_self(&self) -> Siblings<T>;
+	fn following_siblings(&self) -> FollowingSiblings<T>;
+	fn preceding_siblings(&self) -> PrecedingSiblings<T>;
+	fn snapshot(&self) -> Snapshot<T>;
+}
+
+impl<T: Debug + Clone> IterNode<T> for Node<T> {
+	/// Iterates over every window of `size` consecutive siblings, starting from `&self`
+	/// and moving in the `next` direction. Windows overlap like `slice::windows`.
+	///
+	/// Panics if `size` is 0.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1), node!(2), node!(3));
+	///		let windows: Vec<Vec<i32>> = node.child().unwrap()
+	///			.sibling_windows(2)
+	///			.map(|w| w.into_iter().map(|n| n.to_content()).collect())
+	///			.collect();
+	///
+	///		assert_eq!(windows, vec![vec![1, 2], vec![2, 3]]);
+	/// }
+	/// ```
+	fn sibling_windows(&self, size: usize) -> SiblingWindows<T> {
+		assert!(size != 0, "window size must be non-zero");
+
+		SiblingWindows {
+			next: Some(self.clone()),
+			window: VecDeque::with_capacity(size),
+			size
+		}
+	}
+
+	/// Iterates over groups of `size` consecutive siblings, starting from `&self`
+	/// and moving in the `next` direction. Groups don't overlap, like `slice::chunks`.
+	///
+	/// Panics if `size` is 0.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1), node!(2), node!(3));
+	///		let pages: Vec<usize> = node.child().unwrap()
+	///			.sibling_chunks(2)
+	///			.map(|page| page.len())
+	///			.collect();
+	///
+	///		assert_eq!(pages, vec![2, 1]);
+	/// }
+	/// ```
+	fn sibling_chunks(&self, size: usize) -> SiblingChunks<T> {
+		assert!(size != 0, "chunk size must be non-zero");
+
+		SiblingChunks {
+			next: Some(self.clone()),
+			size
+		}
+	}
+
+	/// Streams the subtree of `&self` ( `&self` included ) as `Start`/`End` events
+	/// in document order, so it can be fed into any event-based serializer.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::iter::NodeEvent;
+	///
+	/// fn main() {
+	///		let node = node!("div", node!("p"), node!("span"));
+	///		let mut html = String::new();
+	///		let mut open = Vec::new();
+	///
+	///		for event in node.events() {
+	///			match event {
+	///				NodeEvent::Start(tag) => {
+	///					html.push_str(&format!("<{}>", tag));
+	///					open.push(tag);
+	///				},
+	///				NodeEvent::End => {
+	///					html.push_str(&format!("</{}>", open.pop().unwrap()));
+	///				}
+	///			}
+	///		}
+	///
+	///		assert_eq!(html, "<div><p></p><span></span></div>");
+	/// }
+	/// ```
+	fn events(&self) -> Events<T> {
+		Events {
+			next: Some(self.clone()),
+			open: Vec::new()
+		}
+	}
+
+	/// Iterates over every `Node` after `&self` in document order, up to the end of the linked list.
+	/// The descendants of `&self` come first, as they follow it in document order.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2, node!(3)), node!(4)), node!(5));
+	///		let two = list.first().unwrap().child().unwrap();
+	///
+	///		let after: Vec<i32> = two.following().map(|n| n.to_content()).collect();
+	///		assert_eq!(after, vec![3, 4, 5]);
+	///
+	///		let before: Vec<i32> = two.preceding().map(|n| n.to_content()).collect();
+	///		assert_eq!(before, vec![1]);
+	/// }
+	/// ```
+	fn following(&self) -> Following<T> {
+		Following {
+			next: self.next_in_document()
+		}
+	}
+
+	/// Iterates backwards over every `Node` before `&self` in document order, up to the first
+	/// node of the linked list. The ancestors of `&self` are included, as they precede it in document order.
+	fn preceding(&self) -> Preceding<T> {
+		Preceding {
+			next: self.prev_in_document()
+		}
+	}
+
+	/// Iterates over the descendants of `&self` without children, in document order.
+	/// Yields nothing if `&self` doesn't have any children.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("dir",
+	///			node!("a.txt"),
+	///			node!("sub", node!("b.txt"), node!("c.txt")),
+	///			node!("d.txt")
+	///		);
+	///
+	///		let files: Vec<&str> = node.leaves().map(|n| n.to_content()).collect();
+	///		assert_eq!(files, vec!["a.txt", "b.txt", "c.txt", "d.txt"]);
+	/// }
+	/// ```
+	fn leaves(&self) -> Leaves<T> {
+		Leaves {
+			root: self.clone(),
+			next: self.first_leaf()
+		}
+	}
+
+	/// Lazily iterates over the descendants of `&self` ( `&self` excluded ) in document order.
+	/// Call `Descendants::skip_subtree` to prune the branch of the node just yielded,
+	/// instead of filtering out each one of its descendants.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("root",
+	///			node!("src", node!("lib.rs")),
+	///			node!("target", node!("debug", node!("build"))),
+	///			node!("Cargo.toml")
+	///		);
+	///
+	///		let mut visited = Vec::new();
+	///		let mut walk = node.descendants();
+	///
+	///		while let Some(n) = walk.next() {
+	///			let name = n.to_content();
+	///			visited.push(name);
+	///
+	///			if name == "target" {
+	///				walk.skip_subtree();
+	///			}
+	///		}
+	///
+	///		assert_eq!(visited, vec!["src", "lib.rs", "target", "Cargo.toml"]);
+	/// }
+	/// ```
+	///
+	/// Being lazy, it composes with the standard adaptors without collecting the subtree first:
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1, node!(2), node!(3)), node!(4, node!(5)));
+	///
+	///		let first_even = node.descendants().find(|n| n.get().content % 2 == 0);
+	///		assert_eq!(first_even.unwrap().to_content(), 2);
+	///
+	///		let odd: Vec<i32> = node.descendants()
+	///			.map(|n| n.to_content())
+	///			.filter(|c| c % 2 == 1)
+	///			.take(2)
+	///			.collect();
+	///
+	///		assert_eq!(odd, vec![1, 3]);
+	/// }
+	/// ```
+	fn descendants(&self) -> Descendants<T> {
+		Descendants {
+			root: self.clone(),
+			next: self.child(),
+			last: None
+		}
+	}
+
+	/// Lazily iterates over the descendants of `&self` ( `&self` excluded ) in document order,
+	/// yielding `(depth, node)` pairs, the children of `&self` being at depth 1. The depths are tracked
+	/// while walking, refer to `Descendants::depth`. The pairs are shaped like the ones of `IterNode::levels`,
+	/// which walks breadth-first instead.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("ul", node!("li", node!("ul", node!("li"))), node!("li"));
+	///
+	///		let outline: Vec<String> = node.descendants_with_depth()
+	///			.map(|(depth, n)| format!("{}{}", "-".repeat(depth), n.to_content()))
+	///			.collect();
+	///
+	///		assert_eq!(outline, vec!["-li", "--ul", "---li", "-li"]);
+	///
+	///		let shallow = node.descendants_with_depth().filter(|(depth, _)| *depth <= 2).count();
+	///		assert_eq!(shallow, 3);
+	/// }
+	/// ```
+	fn descendants_with_depth(&self) -> DescendantsWithDepth<T> {
+		self.descendants().with_depth().map(|(node, depth)| (depth, node))
+	}
+
+	/// Iterates over the subtree of `&self` level by level, yielding `(depth, node)` pairs.
+	/// `&self` comes first at depth 0. As depths never decrease, `take_while` can be used
+	/// to stop at a given depth without visiting the deeper levels.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(4)), node!(3));
+	///
+	///		let order: Vec<(usize, i32)> = node.levels().map(|(d, n)| (d, n.to_content())).collect();
+	///		assert_eq!(order, vec![(0, 1), (1, 2), (1, 3), (2, 4)]);
+	///
+	///		let shallow = node.levels().take_while(|(depth, _)| *depth < 2).count();
+	///		assert_eq!(shallow, 3);
+	/// }
+	/// ```
+	fn levels(&self) -> Levels<T> {
+		let mut queue = VecDeque::new();
+		queue.push_back((0, self.clone()));
+
+		Levels {
+			queue
+		}
+	}
+
+	/// Iterates over the subtree of `&self` ( `&self` included ) level by level: the nodes at depth 1 come
+	/// right after `&self`, then the ones at depth 2 and so on. Unlike `collect_children`, which walks
+	/// in document order, siblings are visited before the children of any of them.
+	/// The pending nodes are kept in a queue, so only the frontier is held in memory.
+	/// Call `BreadthFirst::depth` or `BreadthFirst::with_depth` to know the level of every node.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("a", node!("b", node!("d"), node!("e")), node!("c", node!("f")));
+	///
+	///		let order: Vec<&str> = node.breadth_first().map(|n| n.to_content()).collect();
+	///		assert_eq!(order, vec!["a", "b", "c", "d", "e", "f"]);
+	///
+	///		let mut walk = node.breadth_first();
+	///		let mut deepest = Vec::new();
+	///
+	///		while let Some(n) = walk.next() {
+	///			if walk.depth() == 2 {
+	///				deepest.push(n.to_content());
+	///			}
+	///		}
+	///
+	///		assert_eq!(deepest, vec!["d", "e", "f"]);
+	///
+	///		let (depth, last) = node.breadth_first().with_depth().last().unwrap();
+	///		assert_eq!((depth, last.to_content()), (2, "f"));
+	/// }
+	/// ```
+	fn breadth_first(&self) -> BreadthFirst<T> {
+		BreadthFirst {
+			inner: self.levels(),
+			depth: 0
+		}
+	}
+
+	/// Iterates over the ancestors of `&self` ( `&self` excluded ) following the `parent` pointers,
+	/// from the parent up to the root-level node.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("html", node!("body", node!("div", node!("p"))));
+	///		let p = node.child().unwrap().child().unwrap().child().unwrap();
+	///
+	///		let chain: Vec<&str> = p.ancestors().map(|n| n.to_content()).collect();
+	///		assert_eq!(chain, vec!["div", "body", "html"]);
+	///
+	///		assert_eq!(p.ancestors().count(), 3);
+	///		assert!(p.ancestors().any(|n| n.get().content == "body"));
+	///		assert!(node.ancestors().next().is_none());
+	/// }
+	/// ```
+	fn ancestors(&self) -> Ancestors<T> {
+		Ancestors {
+			next: self.parent()
+		}
+	}
+
+	/// Lazily iterates over the direct children of `&self`, from the first one following the `next` pointers.
+	/// Unlike `collect_children`, the grandchildren are not visited and nothing gets collected.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("ul", node!("li", node!("a")), node!("li"), node!("li", node!("b")));
+	///
+	///		assert_eq!(node.children().count(), 3);
+	///
+	///		let nested: Vec<usize> = node.children().map(|li| li.children().count()).collect();
+	///		assert_eq!(nested, vec![1, 0, 1]);
+	///
+	///		assert!(node!("empty").children().next().is_none());
+	/// }
+	/// ```
+	fn children(&self) -> Children<T> {
+		Children {
+			next: self.child()
+		}
+	}
+
+	/// Iterates over the siblings of `&self` ( `&self` excluded ) in document order, the ones before it
+	/// first. Works at the root-level too, where the siblings are the other root-level nodes.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("ul", node!("a"), node!("b"), node!("c"), node!("d"));
+	///		let c = node.child().unwrap().get_sibling(2).unwrap();
+	///
+	///		let others: Vec<&str> = c.siblings().map(|n| n.to_content()).collect();
+	///		assert_eq!(others, vec!["a", "b", "d"]);
+	///
+	///		let all: Vec<&str> = c.siblings_and_self().map(|n| n.to_content()).collect();
+	///		assert_eq!(all, vec!["a", "b", "c", "d"]);
+	///
+	///		assert_eq!(node!("only").siblings().count(), 0);
+	/// }
+	/// ```
+	fn siblings(&self) -> Siblings<T> {
+		Siblings {
+			skip: Some(self.clone()),
+			..self.siblings_and_self()
+		}
+	}
+
+	/// Iterates over the siblings of `&self` in document order, `&self` included at its position.
+	fn siblings_and_self(&self) -> Siblings<T> {
+		let mut first = self.clone();
+
+		while let Some(prev) = first.prev() {
+			first = prev;
+		}
+
+		Siblings {
+			next: Some(first),
+			skip: None
+		}
+	}
+
+	/// Iterates over the siblings after `&self` ( `&self` excluded ), walking the `next` pointers.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1), node!(2), node!(3), node!(4));
+	///		let two = node.child().unwrap().next().unwrap();
+	///
+	///		let after: Vec<i32> = two.following_siblings().map(|n| n.to_content()).collect();
+	///		assert_eq!(after, vec![3, 4]);
+	///
+	///		let before: Vec<i32> = two.preceding_siblings().map(|n| n.to_content()).collect();
+	///		assert_eq!(before, vec![1]);
+	///
+	///		let even = two.following_siblings().find(|n| n.get().content % 2 == 0);
+	///		assert_eq!(even.unwrap().to_content(), 4);
+	/// }
+	/// ```
+	fn following_siblings(&self) -> FollowingSiblings<T> {
+		FollowingSiblings {
+			next: self.next()
+		}
+	}
+
+	/// Iterates backwards over the siblings before `&self` ( `&self` excluded ), walking the `prev` pointers.
+	/// The closest sibling comes first.
+	fn preceding_siblings(&self) -> PrecedingSiblings<T> {
+		PrecedingSiblings {
+			next: self.prev()
+		}
+	}
+
+	/// Captures weak handles to the subtree of `&self` ( `&self` included ) in document order,
+	/// and iterates over them. The walk doesn't follow the pointers while iterating, so the structure
+	/// can be freely modified in the meantime: nodes dropped before being reached are skipped,
+	/// nodes added after the snapshot are not yielded.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2), node!(3), node!(4));
+	///		let mut visited = Vec::new();
+	///
+	///		for n in node.snapshot() {
+	///			if n.get().content % 2 == 0 {
+	///				n.detach();
+	///			}
+	///			visited.push(n.to_content());
+	///		}
+	///
+	///		assert_eq!(visited, vec![1, 2, 3, 4]);
+	///		assert_eq!(node.get_last_child().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	fn snapshot(&self) -> Snapshot<T> {
+		let mut nodes = vec![self.downgrade()];
+		let mut next = next_in_subtree(self, self);
+
+		while let Some(node) = next {
+			next = next_in_subtree(&node, self);
+			nodes.push(node.downgrade());
+		}
+
+		Snapshot {
+			nodes: nodes.into_iter()
+		}
+	}
+}
@@ -206,10 +206,52 @@
 //!   
 //!   ```
 
+// doc examples are indented with tabs across the crate.
+#![allow(clippy::tabs_in_doc_comments)]
+// the examples above wrap their statements in `fn main`: the lint level of the crate docs
+// can only be set on the crate itself.
+#![allow(clippy::needless_doctest_main)]
+
 pub mod node;
 pub mod cell;
 pub mod errors;
 pub mod list;
+pub mod iter;
+pub mod pattern;
+pub mod path;
+pub mod schema;
+pub mod builder;
+pub mod identity;
+pub mod any;
+pub mod search;
+pub mod aggregate;
+pub mod range;
+pub mod audit;
+pub mod bracket;
+pub mod oplog;
+pub mod watch;
+pub mod cache;
+pub mod live;
+pub mod freeze;
+pub mod succinct;
+#[cfg(feature = "async")]
+pub mod stream;
+#[cfg(feature = "test-support")]
+pub mod arbitrary;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "intern")]
+pub mod intern;
+#[cfg(feature = "crdt")]
+pub mod crdt;
+#[cfg(feature = "newick")]
+pub mod newick;
+#[cfg(feature = "metadata")]
+pub mod meta;
+#[cfg(feature = "tags")]
+pub mod tag;
+#[cfg(feature = "postcard")]
+pub mod binary;
 
 pub mod prelude {
 	pub use crate::node::{
@@ -221,6 +263,14 @@ pub mod prelude {
 		InsertNode,
 		CompareNode
 	};
+	pub use crate::iter::{
+		IterNode,
+		CheckedIter
+	};
+	pub use crate::pattern::MatchNode;
+	pub use crate::path::PathNode;
+	pub use crate::search::SearchNode;
+	pub use crate::aggregate::AggregateNode;
 }
 
 pub use node::{
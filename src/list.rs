@@ -1,5 +1,12 @@
 use crate::{
 	cell::HedelCell,
+	oplog::TreeOp,
+	watch::TreeEvent,
+	range::RunIter,
+	node::{
+		WeakNode,
+		GetNode
+	},
 	Node,
 };
 use std::{
@@ -7,9 +14,65 @@ use std::{
 		Rc,
 		Weak
 	},
+	cell::Cell,
 	fmt::Debug,
+	sync::mpsc::Sender,
 };
 
+/// The number of root-level nodes and the last one, kept up to date by `List::adopt_roots`
+/// and `List::release_roots`.
+#[derive(Debug)]
+pub(crate) struct RootSpan<T: Debug + Clone> {
+	count: usize,
+	last: Option<WeakNode<T>>
+}
+
+impl<T: Debug + Clone> Default for RootSpan<T> {
+	fn default() -> Self {
+		Self {
+			count: 0,
+			last: None
+		}
+	}
+}
+
+thread_local! {
+	/// The number of linked lists on this thread whose structural changes are observed, through
+	/// `List::generation`, `List::start_recording` or `List::watch`. While there is none,
+	/// structural changes don't look for the `List` to notify.
+	static OBSERVED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Returns `true` if the structural changes of any linked list on this thread are observed.
+pub(crate) fn observed() -> bool {
+	OBSERVED.with(|observed| observed.get() > 0)
+}
+
+/// The mutation generation of a linked list, only moving once the linked list is observed.
+#[derive(Debug, Default)]
+pub(crate) struct Generation {
+	value: Cell<u64>,
+	observed: Cell<bool>
+}
+
+impl Generation {
+	/// Makes the structural changes of the linked list reach it from now on.
+	pub(crate) fn observe(&self) {
+		if !self.observed.replace(true) {
+			OBSERVED.with(|observed| observed.set(observed.get() + 1));
+		}
+	}
+}
+
+impl Drop for Generation {
+	fn drop(&mut self) {
+		if self.observed.get() {
+			// the thread-local might be gone already when the thread exits.
+			let _ = OBSERVED.try_with(|observed| observed.set(observed.get() - 1));
+		}
+	}
+}
+
 /// `List` concreatly is a pointer to its first node.
 /// This design allows for sibling nodes at the root-level.
 /// 
@@ -36,34 +99,150 @@ use std::{
 
 #[derive(Debug, Clone)]
 pub struct List<T: Debug + Clone> {
-	pub first: Rc<HedelCell<Option<Node<T>>>>
+	pub first: Rc<HedelCell<Option<Node<T>>>>,
+	/// Bumped by every structural change in the linked list, once observed.
+	pub(crate) generation: Rc<Generation>,
+	/// The structural operations recorded so far, `None` when not recording.
+	pub(crate) ops: Rc<HedelCell<Option<Vec<TreeOp<T>>>>>,
+	/// The channels notified of every structural change, refer to `List::watch`.
+	pub(crate) watchers: Rc<HedelCell<Vec<Sender<TreeEvent>>>>,
+	/// The root-level span, refer to `List::root_count`.
+	pub(crate) roots: Rc<HedelCell<RootSpan<T>>>,
+	/// The pool shared by the `Interned` contents, refer to `List::intern`.
+	#[cfg(feature = "intern")]
+	pub(crate) interner: Rc<HedelCell<crate::intern::Interner>>
 }
 
 /// A weak pointer to the the first node of a list.
-/// Necessaraly stored in `Node` to act on the original `List`,
+/// Necessaraly stored in every root-level `Node` to act on the original `List`,
 /// to change its first node.
 ///
 /// Usually built by calling `List::downgrade`.
 #[derive(Debug, Clone)]
 pub struct WeakList<T: Debug + Clone> {
-	pub first: Weak<HedelCell<Option<Node<T>>>>
+	pub first: Weak<HedelCell<Option<Node<T>>>>,
+	pub(crate) generation: Weak<Generation>,
+	pub(crate) ops: Weak<HedelCell<Option<Vec<TreeOp<T>>>>>,
+	pub(crate) watchers: Weak<HedelCell<Vec<Sender<TreeEvent>>>>,
+	pub(crate) roots: Weak<HedelCell<RootSpan<T>>>,
+	#[cfg(feature = "intern")]
+	pub(crate) interner: Weak<HedelCell<crate::intern::Interner>>
 }
 
 impl<T: Debug + Clone> List<T> {
 	
-	/// Builds a `List` properly given its first node 
+	/// Builds a `List` properly given its first node, along with the siblings following it.
 	pub fn new(node: Node<T>) -> Self {
 		let list = Self {
-			first: Rc::new(HedelCell::new(Some(node.clone())))
+			first: Rc::new(HedelCell::new(Some(node.clone()))),
+			generation: Rc::new(Generation::default()),
+			ops: Rc::new(HedelCell::new(None)),
+			watchers: Rc::new(HedelCell::new(Vec::new())),
+			roots: Rc::new(HedelCell::new(RootSpan::default())),
+			#[cfg(feature = "intern")]
+			interner: Rc::new(HedelCell::new(Default::default()))
 		};
-		node.get_mut().list = Some(list.downgrade());
+		let last = node.get_last_sibling().unwrap_or_else(|| node.clone());
+		list.adopt_roots(&node, &last);
 		list
 	}
 	
+	/// Builds a `List` without any node.
+	pub fn empty() -> Self {
+		Self {
+			first: Rc::new(HedelCell::new(None)),
+			generation: Rc::new(Generation::default()),
+			ops: Rc::new(HedelCell::new(None)),
+			watchers: Rc::new(HedelCell::new(Vec::new())),
+			roots: Rc::new(HedelCell::new(RootSpan::default())),
+			#[cfg(feature = "intern")]
+			interner: Rc::new(HedelCell::new(Default::default()))
+		}
+	}
+
+	/// Builds a `List` by linking the given nodes as root-level siblings, in order.
+	/// WARNING: the nodes are expected to be stand-alone ( e.g freshly built or detached ),
+	/// their `parent`, `prev` and `next` pointers get overwritten.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = List::from_nodes(vec![node!(1), node!(2), node!(3)]);
+	///		assert_eq!(list.first().unwrap().get_last_sibling().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	pub fn from_nodes(nodes: Vec<Node<T>>) -> Self {
+		let mut prev: Option<Node<T>> = None;
+
+		for node in nodes.iter() {
+			{
+				let mut borrow = node.get_mut();
+				borrow.parent = None;
+				borrow.next = None;
+				borrow.list = None;
+				borrow.prev = prev.as_ref().map(|p| p.downgrade());
+			}
+
+			if let Some(ref p) = prev {
+				p.get_mut().next = Some(node.clone());
+			}
+
+			prev = Some(node.clone());
+		}
+
+		match nodes.into_iter().next() {
+			Some(first) => Self::new(first),
+			None => Self::empty()
+		}
+	}
+
+	/// Builds a `List` of two-level trees in a single pass, linking the pointers directly:
+	/// every pair holds the content of a root-level node and the contents of its children.
+	/// Skips the per-node bookkeeping of the macros, which adds up for large static trees.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = List::from_nested_slices(&[
+	///			("fruits", &["apple", "pear"][..]),
+	///			("empty", &[]),
+	///			("vegetables", &["leek"])
+	///		]);
+	///
+	///		let fruits = list.first().unwrap();
+	///		assert_eq!(fruits.get_last_child().unwrap().to_content(), "pear");
+	///		assert!(fruits.next().unwrap().child().is_none());
+	///		assert_eq!(list.get_last_sibling().unwrap().child().unwrap().parent().unwrap().to_content(), "vegetables");
+	/// }
+	/// ```
+	pub fn from_nested_slices(trees: &[(T, &[T])]) -> Self {
+		let roots: Vec<Node<T>> = trees.iter().map(|(content, children)| Node::from_slice(content.clone(), children)).collect();
+		link_siblings(&roots, None);
+
+		match roots.into_iter().next() {
+			Some(first) => Self::new(first),
+			None => Self::empty()
+		}
+	}
+
 	/// Get a weak pointer to the first node.
 	pub fn downgrade(&self) -> WeakList<T> {
 		WeakList::<T> {
-			first: Rc::downgrade(&self.first) 
+			first: Rc::downgrade(&self.first),
+			generation: Rc::downgrade(&self.generation),
+			ops: Rc::downgrade(&self.ops),
+			watchers: Rc::downgrade(&self.watchers),
+			roots: Rc::downgrade(&self.roots),
+			#[cfg(feature = "intern")]
+			interner: Rc::downgrade(&self.interner)
 		}
 	}
 	
@@ -83,17 +262,291 @@ impl<T: Debug + Clone> List<T> {
 	pub fn first(&self) -> Option<Node<T>> {
 		self.first.get().clone()	
 	}
+
+	/// Get the first root-level node of the linked list, if any. Same as `List::first`.
+	pub fn get_first_sibling(&self) -> Option<Node<T>> {
+		self.first()
+	}
+
+	/// Get the last root-level node of the linked list, if any.
+	/// Unlike `GetNode::get_last_sibling`, this returns the first node when it is the only one at the root-level.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2)), node!(3), node!(4));
+	///		assert_eq!(list.get_first_sibling().unwrap().to_content(), 1);
+	///		assert_eq!(list.get_last_sibling().unwrap().to_content(), 4);
+	///
+	///		let single = list!(node!(5));
+	///		assert_eq!(single.get_last_sibling().unwrap().to_content(), 5);
+	///		assert!(List::<i32>::empty().get_last_sibling().is_none());
+	/// }
+	/// ```
+	pub fn get_last_sibling(&self) -> Option<Node<T>> {
+		self.last()
+	}
+
+	/// Get the last root-level node of the linked list, if any, in O(1): it is kept up to date
+	/// by the structural changes at the root-level.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1), node!(2));
+	///		assert_eq!(list.last().unwrap().to_content(), 2);
+	///
+	///		list.last().unwrap().append_next(node!(3));
+	///		assert_eq!(list.last().unwrap().to_content(), 3);
+	///
+	///		list.first().unwrap().detach();
+	///		assert_eq!(list.first().unwrap().to_content(), 2);
+	///		assert_eq!(list.last().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	pub fn last(&self) -> Option<Node<T>> {
+		self.roots.get().last.as_ref()?.upgrade()
+	}
+
+	/// Returns the number of root-level nodes. Like `List::last`, it is kept up to date
+	/// instead of being counted.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2)), node!(3));
+	///		assert_eq!(list.root_count(), 2);
+	///
+	///		list.first().unwrap().append_prev(node!(0));
+	///		list.first().unwrap().next().unwrap().append_child(node!(4));
+	///		assert_eq!(list.root_count(), 3);
+	///
+	///		list.last().unwrap().detach();
+	///		assert_eq!(list.root_count(), 2);
+	///		assert_eq!(List::<i32>::empty().root_count(), 0);
+	/// }
+	/// ```
+	pub fn root_count(&self) -> usize {
+		self.roots.get().count
+	}
+
+	/// Get the current mutation generation. Every structural change performed through
+	/// the `AppendNode`, `InsertNode` and `DetachNode` traits bumps it, so comparing two
+	/// readings tells if the linked list changed in between.
+	///
+	/// The generation only starts moving after the first reading: until a linked list on the
+	/// thread is observed, structural changes skip looking for the `List` to bump.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2)));
+	///		let before = list.generation();
+	///
+	///		list.first().unwrap().append_child(node!(3));
+	///		assert!(list.generation() > before);
+	/// }
+	/// ```
+	pub fn generation(&self) -> u64 {
+		self.observe();
+		self.generation.value.get()
+	}
+
+	/// Makes the structural changes of the linked list reach it from now on, refer to `observed`.
+	pub(crate) fn observe(&self) {
+		self.generation.observe();
+	}
+
+	/// Bumps the mutation generation.
+	/// Call it after changing the pointers of the nodes manually through `Node::get_mut`.
+	pub fn bump_generation(&self) {
+		let value = &self.generation.value;
+		value.set(value.get().wrapping_add(1));
+	}
+
+	/// Puts the back-pointer to the linked list on the root-level nodes from `first` to `last`,
+	/// once they are linked, and makes `first` the first node if nothing precedes it.
+	pub(crate) fn adopt_roots(&self, first: &Node<T>, last: &Node<T>) {
+		let handle = self.downgrade();
+		let mut count = 0;
+
+		for node in RunIter::new(first, last) {
+			node.get_mut().list = Some(handle.clone());
+			count += 1;
+		}
+
+		if first.prev().is_none() {
+			*self.first.get_mut() = Some(first.clone());
+		}
+
+		let mut roots = self.roots.get_mut();
+		roots.count += count;
+
+		if last.next().is_none() {
+			roots.last = Some(last.downgrade());
+		}
+	}
+
+	/// Clears the back-pointer of the root-level nodes from `first` to `last`, before they get unlinked,
+	/// and hands the linked list over to the node after `last` if `first` is the first node.
+	pub(crate) fn release_roots(&self, first: &Node<T>, last: &Node<T>) {
+		let mut count = 0;
+
+		for node in RunIter::new(first, last) {
+			node.get_mut().list = None;
+			count += 1;
+		}
+
+		if first.prev().is_none() {
+			*self.first.get_mut() = last.next();
+		}
+
+		let mut roots = self.roots.get_mut();
+		roots.count -= count;
+
+		if last.next().is_none() {
+			roots.last = first.prev().map(|prev| prev.downgrade());
+		}
+	}
+
+	/// Empties the linked list without touching the nodes, returning its first node if any.
+	/// The caller is responsible for clearing their back-pointer to the linked list.
+	pub(crate) fn take_roots(&self) -> Option<Node<T>> {
+		*self.roots.get_mut() = RootSpan::default();
+		self.first.get_mut().take()
+	}
+}
+
+/// Builds a `List` of stand-alone root-level nodes, one for every content, in order.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let list = List::from(vec![1, 2, 3]);
+///		assert_eq!(list.first().unwrap().get_last_sibling().unwrap().to_content(), 3);
+///
+///		let mixed = list!(0, node!(1, 2));
+///		assert_eq!(mixed.first().unwrap().next().unwrap().child().unwrap().to_content(), 2);
+/// }
+/// ```
+impl<T: Debug + Clone> From<Vec<T>> for List<T> {
+	fn from(contents: Vec<T>) -> Self {
+		Self::from_nodes(contents.into_iter().map(Node::new).collect())
+	}
+}
+
+/// Links `nodes` as consecutive siblings under `parent`, and returns the first one.
+/// The nodes are expected to be freshly built.
+fn link_siblings<T: Debug + Clone>(nodes: &[Node<T>], parent: Option<&Node<T>>) -> Option<Node<T>> {
+	let parent = parent.map(|p| p.downgrade());
+
+	for pair in nodes.windows(2) {
+		pair[0].get_mut().next = Some(pair[1].clone());
+		pair[1].get_mut().prev = Some(pair[0].downgrade());
+	}
+
+	if parent.is_some() {
+		for node in nodes {
+			node.get_mut().parent = parent.clone();
+		}
+	}
+
+	nodes.first().cloned()
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Builds a node with one child for every content of `children`, in a single pass.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = Node::from_slice(0, &[1, 2, 3]);
+	///		assert_eq!(node.get_last_child().unwrap().prev().unwrap().to_content(), 2);
+	/// }
+	/// ```
+	pub fn from_slice(content: T, children: &[T]) -> Self {
+		let node = Node::new(content);
+		let children: Vec<Node<T>> = children.iter().cloned().map(Node::new).collect();
+		node.get_mut().child = link_siblings(&children, Some(&node));
+		node
+	}
 }
 
 impl<T: Debug + Clone> WeakList<T> {
 	pub fn upgrade(&self) -> Option<List<T>> {
 		Some(List::<T> {
-			first: self.first.upgrade()?
+			first: self.first.upgrade()?,
+			generation: self.generation.upgrade()?,
+			ops: self.ops.upgrade()?,
+			watchers: self.watchers.upgrade()?,
+			roots: self.roots.upgrade()?,
+			#[cfg(feature = "intern")]
+			interner: self.interner.upgrade()?
 		})
 	}
 }
 
+/// Lets the `list` macro accept both nodes and bare contents: unlike `Into`,
+/// the method resolution picks `FromNode` for nodes before falling back to `FromContent`,
+/// without needing the content type to be known.
+#[doc(hidden)]
+pub mod __private {
+	use std::{
+		cell::Cell,
+		fmt::Debug,
+	};
+
+	use crate::Node;
+
+	pub struct Child<X>(pub Cell<Option<X>>);
+
+	pub trait FromNode<T: Debug + Clone> {
+		fn into_child(self) -> Node<T>;
+	}
+
+	impl<T: Debug + Clone> FromNode<T> for Child<Node<T>> {
+		fn into_child(self) -> Node<T> {
+			self.0.take().unwrap()
+		}
+	}
+
+	pub trait FromContent<T: Debug + Clone> {
+		fn into_child(self) -> Node<T>;
+	}
+
+	impl<T: Debug + Clone> FromContent<T> for &Child<T> {
+		fn into_child(self) -> Node<T> {
+			Node::new(self.0.take().unwrap())
+		}
+	}
+}
+
 /// Generate a linked list blazingly fast and append any number of `Nodes`
+/// or bare contents, which get wrapped into stand-alone nodes.
 /// 
 /// # Example
 ///
@@ -118,7 +571,11 @@ macro_rules! list {
 			let mut c = 0;
 
 			$(
-				let n: hedel_rs::Node::<_> = $node.into();
+				let n: hedel_rs::Node::<_> = {
+					#[allow(unused_imports)]
+					use hedel_rs::list::__private::{FromNode, FromContent};
+					hedel_rs::list::__private::Child(std::cell::Cell::new(Some($node))).into_child()
+				};
 				
 				if let Some(_) = n.get().list {
 					lists.push(c as usize);
@@ -0,0 +1,130 @@
+use std::fmt::Debug;
+
+use crate::{
+	cell::HedelCell,
+	node::{
+		Node,
+		CompareNode,
+		NodeCollection
+	},
+	list::List,
+	iter::next_in_subtree,
+};
+
+/// A collection of the nodes of a linked list satisfying an identifier, in document order,
+/// which stays up to date as nodes get attached and detached, like a DOM live `NodeList`.
+///
+/// Every structural change bumps the generation of the linked list ( refer to `List::generation` ):
+/// the members are collected again on the first access following a change.
+/// Changes to the contents are not structural: call `LiveCollection::refresh` if the identifier depends on them.
+///
+/// Usually built by calling `List::live_collect`.
+#[derive(Debug)]
+pub struct LiveCollection<T: Debug + Clone, I: CompareNode<T>> {
+	list: List<T>,
+	ident: I,
+	/// The members along with the generation they were collected at.
+	members: HedelCell<Option<(u64, Vec<Node<T>>)>>
+}
+
+impl<T: Debug + Clone, I: CompareNode<T>> LiveCollection<T, I> {
+	/// Calls `f` with the up to date members.
+	fn with_members<R, F: FnOnce(&Vec<Node<T>>) -> R>(&self, f: F) -> R {
+		let generation = self.list.generation();
+		let stale = self.members.get().as_ref().is_none_or(|(g, _)| *g != generation);
+
+		if stale {
+			let mut nodes = Vec::new();
+			let mut root = self.list.first();
+
+			while let Some(r) = root {
+				let mut next = Some(r.clone());
+
+				while let Some(node) = next {
+					next = next_in_subtree(&node, &r);
+
+					if self.ident.compare(&node) {
+						nodes.push(node);
+					}
+				}
+
+				root = r.next();
+			}
+
+			*self.members.get_mut() = Some((generation, nodes));
+		}
+
+		f(&self.members.get().as_ref().unwrap().1)
+	}
+
+	/// Returns the number of members.
+	pub fn len(&self) -> usize {
+		self.with_members(|nodes| nodes.len())
+	}
+
+	/// Returns `true` if no node satisfies the identifier.
+	pub fn is_empty(&self) -> bool {
+		self.with_members(|nodes| nodes.is_empty())
+	}
+
+	/// Get the member at `index`, in document order.
+	pub fn get(&self, index: usize) -> Option<Node<T>> {
+		self.with_members(|nodes| nodes.get(index).cloned())
+	}
+
+	/// Returns `true` if `node` is a member.
+	pub fn contains(&self, node: &Node<T>) -> bool {
+		self.with_members(|nodes| nodes.iter().any(|n| n.ptr_eq(node)))
+	}
+
+	/// Get the current members as a `NodeCollection`, which doesn't update anymore.
+	pub fn snapshot(&self) -> NodeCollection<T> {
+		self.with_members(|nodes| NodeCollection::from_vec(nodes.clone()))
+	}
+
+	/// Forces the members to be collected again on the next access.
+	pub fn refresh(&self) {
+		*self.members.get_mut() = None;
+	}
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Get a `LiveCollection` of the nodes satisfying the identifier, in document order.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// #[derive(Clone)]
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2)), node!(3));
+	///		let even = list.live_collect(&Even);
+	///		assert_eq!(even.len(), 1);
+	///
+	///		let four = node!(4);
+	///		list.first().unwrap().append_next(four.clone());
+	///		assert!(even.contains(&four));
+	///
+	///		list.first().unwrap().child().unwrap().detach();
+	///		assert_eq!(even.len(), 1);
+	///		assert_eq!(even.get(0).unwrap().to_content(), 4);
+	/// }
+	/// ```
+	pub fn live_collect<I: CompareNode<T> + Clone>(&self, ident: &I) -> LiveCollection<T, I> {
+		LiveCollection {
+			list: self.clone(),
+			ident: ident.clone(),
+			members: HedelCell::new(None)
+		}
+	}
+}
@@ -0,0 +1,108 @@
+use std::{
+	any::{
+		Any,
+		TypeId
+	},
+	collections::HashMap,
+	fmt::Debug,
+};
+
+use crate::{
+	node::Node,
+	any::AnyContent,
+};
+
+/// A side-table of typed values attached to a `Node`, holding at most one value per type.
+/// Lets passes ( layout, styling, analysis ) annotate nodes without changing the content type `T`.
+///
+/// Requires the `metadata` feature.
+#[derive(Debug, Clone, Default)]
+pub struct Metadata {
+	values: HashMap<TypeId, AnyContent>
+}
+
+impl Metadata {
+	/// Inserts a value, returning the previous value of the same type, if any.
+	pub fn insert<M: Any + Debug + Clone>(&mut self, value: M) -> Option<M> {
+		self.values.insert(TypeId::of::<M>(), AnyContent::new(value))
+			.and_then(|old| old.downcast::<M>().ok())
+	}
+
+	/// Get a reference to the value of type `M`, if any.
+	pub fn get<M: Any>(&self) -> Option<&M> {
+		self.values.get(&TypeId::of::<M>())?.downcast_ref::<M>()
+	}
+
+	/// Get a mutable reference to the value of type `M`, if any.
+	pub fn get_mut<M: Any>(&mut self) -> Option<&mut M> {
+		self.values.get_mut(&TypeId::of::<M>())?.downcast_mut::<M>()
+	}
+
+	/// Removes and returns the value of type `M`, if any.
+	pub fn remove<M: Any>(&mut self) -> Option<M> {
+		self.values.remove(&TypeId::of::<M>())?.downcast::<M>().ok()
+	}
+
+	/// Returns `true` if there's a value of type `M`.
+	pub fn contains<M: Any>(&self) -> bool {
+		self.values.contains_key(&TypeId::of::<M>())
+	}
+
+	/// Removes every value.
+	pub fn clear(&mut self) {
+		self.values.clear();
+	}
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Attaches a typed value to the node, returning the previous value of the same type, if any.
+	///
+	/// Requires the `metadata` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// #[derive(Debug, Clone, PartialEq)]
+	/// struct LayoutBox { width: u32 }
+	///
+	/// fn main() {
+	///		let node = node!("div");
+	///		node.insert_meta(LayoutBox { width: 80 });
+	///
+	///		assert_eq!(node.get_meta::<LayoutBox>(), Some(LayoutBox { width: 80 }));
+	///		assert_eq!(node.remove_meta::<LayoutBox>().unwrap().width, 80);
+	///		assert!(!node.has_meta::<LayoutBox>());
+	/// }
+	/// ```
+	pub fn insert_meta<M: Any + Debug + Clone>(&self, value: M) -> Option<M> {
+		self.get_mut().meta.insert(value)
+	}
+
+	/// Get a copy of the value of type `M` attached to the node, if any.
+	pub fn get_meta<M: Any + Clone>(&self) -> Option<M> {
+		self.get().meta.get::<M>().cloned()
+	}
+
+	/// Copy-free alternative to `Node::get_meta`: calls `f` with a reference to the value of type `M`, if any.
+	pub fn with_meta<M: Any, R, F: FnOnce(&M) -> R>(&self, f: F) -> Option<R> {
+		self.get().meta.get::<M>().map(f)
+	}
+
+	/// Calls `f` with a mutable reference to the value of type `M`, if any.
+	pub fn with_meta_mut<M: Any, R, F: FnOnce(&mut M) -> R>(&self, f: F) -> Option<R> {
+		self.get_mut().meta.get_mut::<M>().map(f)
+	}
+
+	/// Detaches and returns the value of type `M` from the node, if any.
+	pub fn remove_meta<M: Any>(&self) -> Option<M> {
+		self.get_mut().meta.remove::<M>()
+	}
+
+	/// Returns `true` if a value of type `M` is attached to the node.
+	pub fn has_meta<M: Any>(&self) -> bool {
+		self.get().meta.contains::<M>()
+	}
+}
@@ -0,0 +1,183 @@
+use std::fmt::Debug;
+
+use crate::{
+	node::{
+		Node,
+		AppendNode
+	},
+	iter::{
+		IterNode,
+		NodeEvent
+	},
+	errors::HedelError,
+};
+
+/// Maps a content to and from a Newick node: its label and the length of the branch
+/// leading to it. Implemented for `(String, Option<f64>)`.
+///
+/// Requires the `newick` feature.
+pub trait NewickContent: Sized {
+	/// Builds the content of a node from its label ( possibly empty ) and its branch length, if any.
+	fn from_newick(label: &str, length: Option<f64>) -> Self;
+	/// The label written for the node, it shouldn't contain any of `(),:;`.
+	fn newick_label(&self) -> String;
+	/// The length of the branch leading to the node, if any.
+	fn branch_length(&self) -> Option<f64>;
+}
+
+impl NewickContent for (String, Option<f64>) {
+	fn from_newick(label: &str, length: Option<f64>) -> Self {
+		(label.to_string(), length)
+	}
+
+	fn newick_label(&self) -> String {
+		self.0.clone()
+	}
+
+	fn branch_length(&self) -> Option<f64> {
+		self.1
+	}
+}
+
+/// Builds a node from the text read after its children, e.g `name:0.5`, `start` being its byte offset.
+fn complete<T: Debug + Clone + NewickContent>(text: &str, start: usize, children: Vec<Node<T>>) -> Result<Node<T>, HedelError> {
+	let (label, length) = match text.rsplit_once(':') {
+		Some((label, length)) => {
+			let length = length.trim().parse::<f64>()
+				.map_err(|_| HedelError::Parse(start, format!("invalid branch length `{}`", length.trim())))?;
+			(label, Some(length))
+		},
+		None => (text, None)
+	};
+
+	let node = Node::new(T::from_newick(label.trim(), length));
+	node.append_children(children);
+	Ok(node)
+}
+
+impl<T: Debug + Clone + NewickContent> Node<T> {
+	/// Parses a tree in Newick format, e.g `(A:0.1,B:0.2,(C:0.3,D:0.4)E:0.5)F;`,
+	/// mapping every label and branch length through `NewickContent`.
+	/// Labels may be empty, the trailing `;` is optional and quoted labels are not supported.
+	///
+	/// Returns `HedelError::Parse` with the byte offset of the error, if the input is malformed.
+	///
+	/// Requires the `newick` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let tree = Node::<(String, Option<f64>)>::parse_newick("(A:0.1,B:0.2,(C:0.3,D:0.4)E:0.5)F;").unwrap();
+	///
+	///		assert_eq!(tree.get().content.0, "F");
+	///		let e = tree.get_last_child().unwrap();
+	///		assert_eq!(e.to_content(), ("E".to_string(), Some(0.5)));
+	///
+	///		assert_eq!(tree.to_newick(), "(A:0.1,B:0.2,(C:0.3,D:0.4)E:0.5)F;");
+	/// }
+	/// ```
+	pub fn parse_newick(input: &str) -> Result<Node<T>, HedelError> {
+		// children gathered so far for every open `(`.
+		let mut open: Vec<Vec<Node<T>>> = Vec::new();
+		// children of the node being read, set when its `)` is found.
+		let mut pending = Vec::new();
+		let mut text_start = 0;
+		let mut root = None;
+
+		for (i, c) in input.char_indices() {
+			if root.is_some() {
+				if !c.is_whitespace() {
+					return Err(HedelError::Parse(i, format!("unexpected `{}` after `;`", c)));
+				}
+
+				continue;
+			}
+
+			match c {
+				'(' => {
+					if !input[text_start..i].trim().is_empty() || !pending.is_empty() {
+						return Err(HedelError::Parse(i, "unexpected `(`".to_string()));
+					}
+
+					open.push(Vec::new());
+				},
+				',' | ')' | ';' => {
+					let node = complete(&input[text_start..i], text_start, std::mem::take(&mut pending))?;
+
+					match (c, open.last_mut()) {
+						(';', None) => root = Some(node),
+						(',', Some(siblings)) => siblings.push(node),
+						(')', Some(siblings)) => {
+							siblings.push(node);
+							// `open` isn't empty, checked by the pattern above.
+							pending = open.pop().unwrap();
+						},
+						_ => return Err(HedelError::Parse(i, format!("unexpected `{}`", c)))
+					}
+				},
+				_ => continue
+			}
+
+			text_start = i + c.len_utf8();
+		}
+
+		if !open.is_empty() {
+			return Err(HedelError::Parse(input.len(), "unclosed `(`".to_string()));
+		}
+
+		match root {
+			Some(root) => Ok(root),
+			None => complete(&input[text_start..], text_start, pending)
+		}
+	}
+
+	/// Writes `&self` and its descendants in Newick format, terminated by `;`.
+	/// Refer to `Node::parse_newick`.
+	///
+	/// Requires the `newick` feature.
+	pub fn to_newick(&self) -> String {
+		let mut output = String::new();
+		let mut contents = Vec::new();
+		let mut last_started = false;
+		let mut first = true;
+
+		for event in self.events() {
+			match event {
+				NodeEvent::Start(content) => {
+					if last_started {
+						output.push('(');
+					} else if !first {
+						output.push(',');
+					}
+
+					contents.push(content);
+					last_started = true;
+					first = false;
+				},
+				NodeEvent::End => {
+					if !last_started {
+						output.push(')');
+					}
+
+					// every `End` matches a previous `Start`.
+					let content = contents.pop().unwrap();
+					output.push_str(&content.newick_label());
+
+					if let Some(length) = content.branch_length() {
+						output.push(':');
+						output.push_str(&length.to_string());
+					}
+
+					last_started = false;
+				}
+			}
+		}
+
+		output.push(';');
+		output
+	}
+}
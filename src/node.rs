@@ -2,10 +2,13 @@ use std::{
 	rc::{
 		Weak,
 		Rc
-	}
+	},
+	cell::Cell,
 };
 
 use std::fmt::Debug;
+use std::collections::HashMap;
+use std::hash::Hash;
 
 use crate::cell::{
 	HedelCell,
@@ -19,6 +22,16 @@ use crate::{
 	}
 };
 use crate::errors::HedelError;
+use crate::oplog::{
+	record,
+	Change
+};
+use crate::iter::{
+	next_in_subtree,
+	skip_in_subtree,
+	IterNode
+};
+use crate::path::PathNode;
 
 /// NodeInner contains pointers in both vertical and horizontal directions
 /// and a custom content field.
@@ -29,6 +42,15 @@ pub struct NodeInner<T: Debug + Clone> {
 	pub child: Option<Node<T>>,
 	pub parent: Option<WeakNode<T>>,
 	pub list: Option<WeakList<T>>,
+	/// Typed annotations attached to the node, refer to `Metadata`.
+	#[cfg(feature = "metadata")]
+	pub meta: crate::meta::Metadata,
+	/// String labels attached to the node, refer to `Node::add_tag`.
+	#[cfg(feature = "tags")]
+	pub tags: Vec<String>,
+	/// Number of `FrozenNode` handles keeping the node frozen, refer to `Node::freeze`.
+	/// A `Cell`, so it's updated through a shared borrow.
+	pub(crate) frozen: Cell<u32>,
 	pub content: T
 }
 
@@ -59,6 +81,49 @@ pub struct Node<T: Debug + Clone > {
 	pub inner: Rc<HedelCell<NodeInner<T>>>,
 }
 
+/// Builds a stand-alone node with the default content,
+/// e.g a placeholder in generic code before the content is known.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let node = Node::<i32>::default();
+///		assert_eq!(node.to_content(), 0);
+/// }
+/// ```
+impl<T: Debug + Clone + Default> Default for Node<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+/// Wraps a bare content into a stand-alone node, so APIs accepting `impl Into<Node<T>>`
+/// ( and the `node` and `list` macros ) work with plain values.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let node = node!(1, 2, node!(3, 4));
+///		assert_eq!(node.child().unwrap().to_content(), 2);
+///
+///		let leaf: Node<&str> = "leaf".into();
+///		assert!(leaf.child().is_none());
+/// }
+/// ```
+impl<T: Debug + Clone> From<T> for Node<T> {
+	fn from(content: T) -> Self {
+		Self::new(content)
+	}
+}
+
 impl<T: Debug + Clone> Clone for Node<T> {
 	fn clone(&self) -> Self {
 		Self {
@@ -79,11 +144,22 @@ impl<T: Debug + Clone> Node<T> {
 				child: None,
 				parent: None,
 				list: None,
+				#[cfg(feature = "metadata")]
+				meta: crate::meta::Metadata::default(),
+				#[cfg(feature = "tags")]
+				tags: Vec::new(),
+				frozen: Cell::new(0),
 				content
 			})),
 		}
 	}
 
+	/// Builds a stand-alone node without children, same as `Node::new`.
+	/// Makes explicit that a node is meant to stay a leaf.
+	pub fn leaf(content: T) -> Self {
+		Self::new(content)
+	}
+
 	/// A `WeakNode` has to be built by downgrading `Node`
 	/// following the same logic to get a `Weak` from a `Rc`
 	pub fn downgrade(&self) -> WeakNode<T> {
@@ -94,20 +170,46 @@ impl<T: Debug + Clone> Node<T> {
 
 	/// Get access to `NodeInner` or return `HedelError` in case 
 	/// the runtime borrow checker in `HedelCell` doesn't allow to get a shared reference.
-	pub fn try_get(&self) -> Result<RefHedel<NodeInner<T>>, HedelError> {
-		Ok(self.inner.try_get()?)
+	pub fn try_get(&self) -> Result<RefHedel<'_, NodeInner<T>>, HedelError> {
+		self.inner.try_get()
 	}
 
 	/// Get access to `NodeInner` or panic! in case 
 	/// the runtime borrow checker in `HedelCell` doesn't allow to get a shared reference.
-	pub fn get(&self) -> RefHedel<NodeInner<T>> {
+	pub fn get(&self) -> RefHedel<'_, NodeInner<T>> {
 		self.inner.get()
 	}
 
 	/// Get mutable access to `NodeInner` or return `HedelError` in case 
 	/// the runtime borrow checker in `HedelCell` doesn't allow to get a mutable reference.
-	pub fn try_get_mut(&self) -> RefMutHedel<'_, NodeInner<T>> {
-		self.inner.get_mut()
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::errors::{HedelError, BorrowKind};
+	///
+	/// fn main() {
+	///		let node = node!(1);
+	///		let borrow = node.get();
+	///
+	///		assert!(matches!(
+	///			node.try_get_mut(),
+	///			Err(HedelError::BorrowConflict { requested: BorrowKind::Mutable, held: BorrowKind::Shared })
+	///		));
+	///
+	///		drop(borrow);
+	///		let _borrow = node.try_get_mut().unwrap();
+	///
+	///		assert_eq!(
+	///			node.try_get().unwrap_err(),
+	///			HedelError::BorrowConflict { requested: BorrowKind::Shared, held: BorrowKind::Mutable }
+	///		);
+	/// }
+	/// ```
+	pub fn try_get_mut(&self) -> Result<RefMutHedel<'_, NodeInner<T>>, HedelError> {
+		self.inner.try_get_mut()
 	}
 
 	/// Get mutable access to `NodeInner` or panic! in case 
@@ -116,6 +218,11 @@ impl<T: Debug + Clone> Node<T> {
 		self.inner.get_mut()
 	}
 
+	/// Returns `true` if both handles point to the same `Node`.
+	pub fn ptr_eq(&self, other: &Node<T>) -> bool {
+		Rc::ptr_eq(&self.inner, &other.inner)
+	}
+
 	/// Get the next `Node` in horizontal direction
 	pub fn next(&self) -> Option<Node<T>> {
 		self.get().next.clone()	
@@ -135,13 +242,82 @@ impl<T: Debug + Clone> Node<T> {
 		} None
 	}
 
-	/// if currently under a NodeList, returns it.
+	/// if currently at the root-level of a NodeList, returns it.
 	pub fn list(&self) -> Option<List<T>> {	
 		if let Some(ref l) = self.get().list {
-			return Some(l.upgrade()?);
+			return l.upgrade();
 		} None
 	}
 
+	/// Promotes `self` to a new `List`, so its subtree becomes a document of its own.
+	/// A node with a parent, or part of another `List`, gets detached first. Otherwise `self` is taken
+	/// along with its siblings, which become the root-level of the new `List`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!("html", node!("body", node!("p"))));
+	///		let body = list.first().unwrap().child().unwrap();
+	///
+	///		let document = body.into_list();
+	///		assert_eq!(document.first().unwrap().child().unwrap().to_content(), "p");
+	///		assert!(document.first().unwrap().parent().is_none());
+	///		assert!(list.first().unwrap().child().is_none());
+	///		assert!(document.audit().is_empty());
+	/// }
+	/// ```
+	pub fn into_list(self) -> List<T> {
+		if self.parent().is_some() || self.list().is_some() {
+			crate::range::unlink_run(&self, &self);
+		}
+
+		let first = self.get_first_sibling().unwrap_or(self);
+		List::new(first)
+	}
+
+	/// Get the `List` the node belongs to, if any, by climbing up to its root-level ancestor,
+	/// which holds the back-pointer.
+	pub fn root_list(&self) -> Option<List<T>> {
+		let mut top = self.clone();
+
+		while let Some(parent) = top.parent() {
+			top = parent;
+		}
+
+		top.list()
+	}
+
+	/// Get the `List` of `&self` if it's a root-level node.
+	pub(crate) fn own_list(&self) -> Option<List<T>> {
+		match self.parent() {
+			Some(_) => None,
+			None => self.list()
+		}
+	}
+
+	/// Bumps the generation of the `List` the node belongs to, if any.
+	/// Called by every structural change, it returns right away if no generation is observed.
+	pub(crate) fn touch(&self) {
+		if !crate::list::observed() {
+			return;
+		}
+
+		if let Some(list) = self.root_list() {
+			list.bump_generation();
+		}
+	}
+
+	/// Records the removal of all the children of `&self`, if its linked list is recording.
+	fn record_children_removal(&self) {
+		if let Some(first) = self.child() {
+			record(Change::Removal(&first, &first.get_last_sibling().unwrap_or_else(|| first.clone())));
+		}
+	}
+
 	/// Get the first child `Node` in vertical direction.
 	pub fn child(&self) -> Option<Node<T>> {
 		self.get().child.clone()
@@ -151,7 +327,53 @@ impl<T: Debug + Clone> Node<T> {
 		self.get().content.clone()	
 	}
 
-	/// Re-set the `parent`, `next` and `prev` fields on the `Node`.
+	/// Walks the descendants of `&self` once, in document order, and calls `update` on the content
+	/// of every one matching the identifier. `&self` is not evaluated, like in `CollectNode::collect_children`.
+	/// Returns the number of updated nodes. Changing the contents isn't a structural change,
+	/// so the generation of the linked list doesn't move.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1, node!(2)), node!(4), node!(5));
+	///
+	///		let updated = node.update_where(&Even, |content| *content *= 10);
+	///		assert_eq!(updated, 2);
+	///
+	///		let contents: Vec<i32> = node.descendants().map(|n| n.to_content()).collect();
+	///		assert_eq!(contents, vec![1, 20, 40, 5]);
+	///		assert_eq!(node.get().content, 0);
+	/// }
+	/// ```
+	pub fn update_where<I: CompareNode<T>, F: FnMut(&mut T)>(&self, ident: &I, mut update: F) -> usize {
+		let mut updated = 0;
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				update(&mut node.get_mut().content);
+				updated += 1;
+			}
+
+			next = next_in_subtree(&node, self);
+		}
+
+		updated
+	}
+
+	/// Re-set the `parent`, `next`, `prev` and `list` fields on the `Node`.
 	/// WARNING: this is meant to be used by `NodeCollection::free` after 
 	/// the `HedelDetach::detach_preserve` function. Refer to it's documentation
 	/// for an usage example. 
@@ -165,6 +387,7 @@ impl<T: Debug + Clone> Node<T> {
 		node.parent = None;
 		node.next = None;
 		node.prev = None;
+		node.list = None;
 	}
 }
 
@@ -199,6 +422,12 @@ macro_rules! as_content {
 pub trait DetachNode<T: Debug + Clone> {
 	fn detach(&self);
 	fn detach_preserve(&self, vec: &mut NodeCollection<T>);
+	fn partition_children<I: CompareNode<T>>(&self, ident: &I) -> (List<T>, List<T>);
+	fn replace_children<I: IntoIterator<Item = Node<T>>>(&self, nodes: I) -> NodeCollection<T>;
+	fn clear_children(&self) -> usize;
+	fn take_children(&self) -> List<T>;
+	fn normalize<F: FnMut(&mut T, &T) -> bool>(&self, merge: F) -> usize;
+	fn detach_matching<I: CompareNode<T>>(&self, ident: &I) -> NodeCollection<T>;
 }
 
 impl<T: Debug + Clone> DetachNode<T> for Node<T> {
@@ -209,6 +438,13 @@ impl<T: Debug + Clone> DetachNode<T> for Node<T> {
 	/// So when you are detecting nodes in a linked-list and detaching them, you cant iterate over them using this method
 	/// as it would break the loop. Use `detach_preserve` instead.
 	fn detach(&self) {
+		self.check_mutable();
+		record(Change::Removal(self, self));
+		self.touch();
+
+		if let Some(list) = self.own_list() {
+			list.release_roots(self, self);
+		}
 						// 1				3
 		let mut tuple: (Option<Node<T>>, Option<Node<T>>) = ( None, None );
 
@@ -324,7 +560,15 @@ impl<T: Debug + Clone> DetachNode<T> for Node<T> {
 	///		detached_nodes.free();
 	/// }
 	/// ```
+	#[allow(clippy::needless_doctest_main)]
 	fn detach_preserve(&self, vec: &mut NodeCollection<T>) {
+		self.check_mutable();
+		record(Change::Removal(self, self));
+		self.touch();
+
+		if let Some(list) = self.own_list() {
+			list.release_roots(self, self);
+		}
 							// 1				3
 		let mut tuple: (Option<Node<T>>, Option<Node<T>>) = ( None, None );
 
@@ -366,6 +610,315 @@ impl<T: Debug + Clone> DetachNode<T> for Node<T> {
 
 		vec.push(self.clone());
 	}
+
+	/// Detaches all the children of `&self` and splits them in two lists: the first one
+	/// holds the children matching the identifier, the second one all the others.
+	/// The children keep their relative order and their own subtrees.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// pub struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			as_content!(node, |content| {
+	///				content % 2 == 0
+	///			})
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1), node!(2), node!(3), node!(4));
+	///		let (even, odd) = node.partition_children(&Even);
+	///
+	///		assert!(node.child().is_none());
+	///		assert_eq!(even.first().unwrap().get_last_sibling().unwrap().to_content(), 4);
+	///		assert_eq!(odd.first().unwrap().next().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	fn partition_children<I: CompareNode<T>>(&self, ident: &I) -> (List<T>, List<T>) {
+		self.check_children_mutable();
+		self.record_children_removal();
+		self.touch();
+
+		let mut matching = Vec::new();
+		let mut others = Vec::new();
+
+		let mut current = self.get_mut().child.take();
+
+		while let Some(child) = current {
+			current = child.next();
+			child.free();
+
+			if ident.compare(&child) {
+				matching.push(child);
+			} else {
+				others.push(child);
+			}
+		}
+
+		(List::from_nodes(matching), List::from_nodes(others))
+	}
+
+	/// Detaches all the children of `&self` and installs the given nodes in their place, in order.
+	/// The old children are returned as stand-alone nodes, each one keeping its own subtree.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("ul", node!("old"), node!("old"));
+	///		let old = node.replace_children(vec![node!("new")]);
+	///
+	///		assert_eq!(old.as_nodes().len(), 2);
+	///		assert!(old.as_nodes()[0].parent().is_none());
+	///		assert_eq!(node.child().unwrap().to_content(), "new");
+	///		assert!(node.child().unwrap().next().is_none());
+	/// }
+	/// ```
+	fn replace_children<I: IntoIterator<Item = Node<T>>>(&self, nodes: I) -> NodeCollection<T> {
+		self.check_children_mutable();
+
+		let nodes: Vec<Node<T>> = nodes.into_iter().collect();
+
+		for node in nodes.iter() {
+			node.check_mutable();
+		}
+
+		self.record_children_removal();
+		self.touch();
+
+		let mut old = NodeCollection::new();
+		let mut current = self.get_mut().child.take();
+
+		while let Some(child) = current {
+			current = child.next();
+			child.free();
+			old.push(child);
+		}
+
+		self.append_children(nodes);
+		old
+	}
+
+	/// Detaches and drops every child of `&self`, returning how many direct children were removed.
+	/// The subtrees are dismantled iteratively, so dropping very long or very deep chains
+	/// can't overflow the stack. Nodes still referenced elsewhere are only detached, and keep their subtree.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(3)), node!(4));
+	///		let four = node.get_last_child().unwrap();
+	///
+	///		assert_eq!(node.clear_children(), 2);
+	///		assert!(node.child().is_none());
+	///		assert!(four.parent().is_none());
+	/// }
+	/// ```
+	fn clear_children(&self) -> usize {
+		self.check_children_mutable();
+		self.record_children_removal();
+		self.touch();
+
+		let mut count = 0;
+		let mut stack = Vec::new();
+		let mut current = self.get_mut().child.take();
+
+		while let Some(child) = current {
+			current = child.next();
+			child.free();
+			stack.push(child);
+			count += 1;
+		}
+
+		while let Some(node) = stack.pop() {
+			// only the handle in `stack` is left: nobody can observe the subtree anymore.
+			if Rc::strong_count(&node.inner) > 1 {
+				continue;
+			}
+
+			let mut borrow = node.get_mut();
+
+			if let Some(next) = borrow.next.take() {
+				stack.push(next);
+			}
+
+			if let Some(child) = borrow.child.take() {
+				stack.push(child);
+			}
+		}
+
+		count
+	}
+
+	/// Moves all the children of `&self` into a new stand-alone `List`, keeping their order
+	/// and their subtrees, so they can be transplanted to another node or linked list.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let from = node!(1, node!(2, node!(3)), node!(4));
+	///		let to = node!(5);
+	///
+	///		let list = from.take_children();
+	///		assert!(from.child().is_none());
+	///		assert!(list.first().unwrap().parent().is_none());
+	///
+	///		list.first().unwrap().get_last_sibling().unwrap().append_next(node!(6));
+	///		to.append_child(node!(0));
+	///		to.child().unwrap().splice_after(list);
+	///		assert_eq!(to.get_last_child().unwrap().to_content(), 6);
+	/// }
+	/// ```
+	fn take_children(&self) -> List<T> {
+		self.check_children_mutable();
+		self.record_children_removal();
+		self.touch();
+
+		let first = match self.get_mut().child.take() {
+			Some(first) => first,
+			None => return List::empty()
+		};
+
+		let mut current = first.clone();
+
+		/* do */ {
+			current.get_mut().parent = None;
+		} while let Some(next) = current.next() {
+			current = next;
+			current.get_mut().parent = None;
+		}
+
+		List::new(first)
+	}
+
+	/// Walks the descendants of `&self` and fuses adjacent siblings: `merge` gets the content
+	/// of a node and of its next sibling, and returns `true` once it merged the second into the first.
+	/// The second node is then removed, and its children get appended to the first one.
+	/// Returns the number of removed nodes. This is the generic version of the DOM text-node normalization.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let p = node!(String::from("p"),
+	///			node!(String::from("#Hello")),
+	///			node!(String::from("#, ")),
+	///			node!(String::from("#world")),
+	///			node!(String::from("b"))
+	///		);
+	///
+	///		let merged = p.normalize(|a, b| {
+	///			if a.starts_with('#') && b.starts_with('#') {
+	///				a.push_str(&b[1..]);
+	///				return true;
+	///			}
+	///			false
+	///		});
+	///
+	///		assert_eq!(merged, 2);
+	///		assert_eq!(p.child().unwrap().to_content(), "#Hello, world");
+	///		assert_eq!(p.child().unwrap().next().unwrap().to_content(), "b");
+	/// }
+	/// ```
+	fn normalize<F: FnMut(&mut T, &T) -> bool>(&self, mut merge: F) -> usize {
+		self.check_mutable();
+
+		let mut removed = 0;
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			while let Some(sibling) = node.next() {
+				if !merge(&mut node.get_mut().content, &sibling.get().content) {
+					break;
+				}
+
+				sibling.detach();
+
+				let mut children = Vec::new();
+				let mut child = sibling.get_mut().child.take();
+
+				while let Some(c) = child {
+					child = c.next();
+					children.push(c);
+				}
+
+				node.append_children(children);
+				removed += 1;
+			}
+
+			next = next_in_subtree(&node, self);
+		}
+
+		removed
+	}
+
+	/// Detaches every descendant of `&self` matching the identifier, in document order, and returns them.
+	/// The subtree of a matching node is detached along with it, and isn't searched any further.
+	/// Performs the `detach_preserve` and `NodeCollection::free` pattern internally,
+	/// so the iteration can't break while nodes get detached.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Comment;
+	///
+	/// impl CompareNode<&'static str> for Comment {
+	///		fn compare(&self, node: &Node<&'static str>) -> bool {
+	///			node.get().content.starts_with("//")
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!("fn", node!("// a"), node!("let", node!("// b")), node!("// c", node!("x")), node!("ret"));
+	///		let removed = node.detach_matching(&Comment);
+	///
+	///		assert_eq!(removed.as_nodes().len(), 3);
+	///		assert!(removed.as_nodes().iter().all(|n| n.parent().is_none() && n.next().is_none()));
+	///		assert_eq!(removed.as_nodes()[2].child().unwrap().to_content(), "x");
+	///
+	///		let left: Vec<&str> = node.descendants().map(|n| n.to_content()).collect();
+	///		assert_eq!(left, vec!["let", "ret"]);
+	/// }
+	/// ```
+	fn detach_matching<I: CompareNode<T>>(&self, ident: &I) -> NodeCollection<T> {
+		let mut detached = NodeCollection::new();
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				next = skip_in_subtree(&node, self);
+				node.detach_preserve(&mut detached);
+			} else {
+				next = next_in_subtree(&node, self);
+			}
+		}
+
+		detached.free();
+		detached
+	}
 }
 
 /// `NodeCollection` represents a `Vec` of `Node`s. Usually retrived by collecting over
@@ -390,6 +943,7 @@ impl<T: Debug + Clone> NodeCollection<T> {
 			nodes: Vec::new()
 		}
 	}
+
 	/// Consume `self` and retrive its `Node`s.
 	pub fn into_nodes(self) -> Vec<Node<T>> {
 		self.nodes
@@ -422,15 +976,169 @@ impl<T: Debug + Clone> NodeCollection<T> {
 		}
 	}
 
-}
-
-impl<T: Debug + Clone> IntoIterator for NodeCollection<T> {
-	type Item = Node<T>;
-	type IntoIter = std::vec::IntoIter<Node<T>>;
-
-	fn into_iter(self) -> Self::IntoIter {
-		self.nodes.into_iter()
-	}
+	/// Consumes the collection, re-setting the pointers of its nodes like `NodeCollection::free`,
+	/// and appends them as the last children of `parent`, in order, keeping their subtrees.
+	/// Completes the `HedelDetach::detach_preserve` pattern when the detached nodes get moved elsewhere.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let todo = node!("todo", node!("a"), node!("done: b"), node!("done: c"));
+	///		let done = node!("done");
+	///
+	///		let mut moved = NodeCollection::new();
+	///		let mut next = todo.child();
+	///
+	///		while let Some(n) = next {
+	///			next = n.next();
+	///
+	///			if n.get().content.starts_with("done") {
+	///				n.detach_preserve(&mut moved);
+	///			}
+	///		}
+	///
+	///		moved.reattach_under(&done);
+	///
+	///		assert!(todo.child().unwrap().next().is_none());
+	///		assert_eq!(done.get_last_child().unwrap().to_content(), "done: c");
+	///		assert!(done.get_last_child().unwrap().parent().unwrap().ptr_eq(&done));
+	/// }
+	/// ```
+	pub fn reattach_under(self, parent: &Node<T>) {
+		self.free();
+		parent.append_children(self.nodes);
+	}
+
+	/// Consumes the collection, re-setting the pointers of its nodes like `NodeCollection::free`,
+	/// and inserts them right after `sibling`, in order, keeping their subtrees.
+	/// Refer to `NodeCollection::reattach_under`.
+	pub fn reattach_after(self, sibling: &Node<T>) {
+		self.free();
+		sibling.splice_after(List::from_nodes(self.nodes));
+	}
+
+	/// Sorts the nodes by their position in the linked list, in document order, using their `NodePath`.
+	/// The sort is stable: nodes at the same path ( e.g duplicates, or nodes from different trees ) keep their relative order.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let list = list!(node!(0, node!(2, node!(4)), node!(6)), node!(8, node!(10)));
+	///		let last = list.first().unwrap().next().unwrap();
+	///
+	///		let mut collection = last.collect_linked_list(&Even);
+	///		collection.sort_document_order();
+	///
+	///		let contents: Vec<i32> = collection.into_iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(contents, vec![0, 2, 4, 6, 8, 10]);
+	/// }
+	/// ```
+	pub fn sort_document_order(&mut self) {
+		self.nodes.sort_by_cached_key(|node| node.path());
+	}
+
+	/// Consume `self` and bucket its `Node`s by the key returned from `f`.
+	/// The relative order of the nodes is preserved inside every bucket.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let collection = NodeCollection::from_vec(vec![node!(1), node!(2), node!(3), node!(4)]);
+	///		let groups = collection.group_by(|node| node.get().content % 2 == 0);
+	///
+	///		assert_eq!(groups[&true].as_nodes().len(), 2);
+	///		assert_eq!(groups[&false].as_nodes()[0].clone().to_content(), 1);
+	/// }
+	/// ```
+	pub fn group_by<K: Eq + Hash, F: FnMut(&Node<T>) -> K>(self, mut f: F) -> HashMap<K, NodeCollection<T>> {
+		let mut groups: HashMap<K, NodeCollection<T>> = HashMap::new();
+
+		for node in self.nodes.into_iter() {
+			groups.entry(f(&node)).or_default().push(node);
+		}
+
+		groups
+	}
+
+}
+
+impl<T: Debug + Clone> Default for NodeCollection<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Debug + Clone> IntoIterator for NodeCollection<T> {
+	type Item = Node<T>;
+	type IntoIter = std::vec::IntoIter<Node<T>>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.nodes.into_iter()
+	}
+}
+
+/// `Node`s are built on `Rc` and can't be sent across threads, so the parallel iterator
+/// runs over a snapshot of the contents, cloned from every `Node` of the collection.
+///
+/// Requires the `sync` feature.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use rayon::prelude::*;
+///
+/// fn main() {
+///		let collection = NodeCollection::from_vec(vec![node!(1), node!(2), node!(3)]);
+///		let sum: i32 = (&collection).into_par_iter().map(|n| n * 2).sum();
+///		assert_eq!(sum, 12);
+///
+///		let max = collection.into_par_iter().max();
+///		assert_eq!(max, Some(3));
+/// }
+/// ```
+#[cfg(feature = "sync")]
+impl<T: Debug + Clone + Send> rayon::iter::IntoParallelIterator for NodeCollection<T> {
+	type Item = T;
+	type Iter = rayon::vec::IntoIter<T>;
+
+	fn into_par_iter(self) -> Self::Iter {
+		(&self).into_par_iter()
+	}
+}
+
+#[cfg(feature = "sync")]
+impl<T: Debug + Clone + Send> rayon::iter::IntoParallelIterator for &NodeCollection<T> {
+	type Item = T;
+	type Iter = rayon::vec::IntoIter<T>;
+
+	fn into_par_iter(self) -> Self::Iter {
+		self.nodes.iter()
+			.map(|node| node.get().content.clone())
+			.collect::<Vec<T>>()
+			.into_par_iter()
+	}
 }
 
 /// Users are supposed to impl `CompareNode` for an enum they would
@@ -466,10 +1174,116 @@ pub trait CompareNode<T: Debug + Clone> {
 	fn compare(&self, node: &Node<T>) -> bool;
 }
 
+/// Wraps an identifier and caches its result for every `Node` it compares, keyed by the node identity.
+/// Useful with expensive identifiers, as traversals like `CollectNode::collect_linked_list`
+/// might compare the same `Node` more than once through overlapping sub-walks.
+///
+/// The cache keeps a `WeakNode` to every compared node, so their memory is only released
+/// once the `Memoized` is dropped or cleared.
+///
+/// WARNING: the cache is only valid as long as the compared nodes and their contents don't change.
+/// Build a new `Memoized` ( or call `Memoized::clear` ) for every traversal.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::node::Memoized;
+/// use std::cell::Cell;
+///
+/// pub struct Expensive<'a>(&'a Cell<usize>);
+///
+/// impl<'a> CompareNode<i32> for Expensive<'a> {
+///		fn compare(&self, node: &Node<i32>) -> bool {
+///			self.0.set(self.0.get() + 1);
+///			as_content!(node, |content| {
+///				content > 1
+///			})
+///		}
+/// }
+///
+/// fn main() {
+///		let calls = Cell::new(0);
+///		let node = node!(1, node!(2), node!(3));
+///		let ident = Memoized::new(Expensive(&calls));
+///
+///		let a = node.collect_linked_list(&ident);
+///		let first_pass = calls.get();
+///		let b = node.collect_linked_list(&ident);
+///
+///		// the second traversal is answered by the cache.
+///		assert_eq!(a.as_nodes().len(), b.as_nodes().len());
+///		assert_eq!(calls.get(), first_pass);
+///
+///		// a node allocated after another one was dropped isn't answered by its entry.
+///		let calls_before = calls.get();
+///
+///		for content in 0..4 {
+///			let node = node!(content);
+///			assert_eq!(ident.compare(&node), content > 1);
+///		}
+///		assert_eq!(calls.get(), calls_before + 4);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Memoized<T: Debug + Clone, I> {
+	ident: I,
+	/// Every entry holds a `WeakNode` to the compared node: as long as it's cached,
+	/// its allocation can't be reused by another `Node` with the same address.
+	cache: HedelCell<HashMap<usize, (WeakNode<T>, bool)>>
+}
+
+impl<T: Debug + Clone, I> Memoized<T, I> {
+	/// Wraps the identifier with an empty cache.
+	pub fn new(ident: I) -> Self {
+		Self {
+			ident,
+			cache: HedelCell::new(HashMap::new())
+		}
+	}
+
+	/// Forgets every cached result.
+	pub fn clear(&self) {
+		self.cache.get_mut().clear();
+	}
+
+	/// Consumes itself and returns the inner identifier.
+	pub fn into_inner(self) -> I {
+		self.ident
+	}
+}
+
+impl<T: Debug + Clone, I: CompareNode<T>> CompareNode<T> for Memoized<T, I> {
+	fn compare(&self, node: &Node<T>) -> bool {
+		let key = Rc::as_ptr(&node.inner) as usize;
+
+		if let Some((_, result)) = self.cache.get().get(&key) {
+			return *result;
+		}
+
+		let result = self.ident.compare(node);
+		self.cache.get_mut().insert(key, (node.downgrade(), result));
+		result
+	}
+}
+
+/// A `NodeCollection` matches the nodes it contains, compared by identity.
+/// Handy as a set of boundary nodes for the bounded traversals ( e.g `FindNode::find_child_bounded` ).
+impl<T: Debug + Clone> CompareNode<T> for NodeCollection<T> {
+	fn compare(&self, node: &Node<T>) -> bool {
+		self.nodes.iter().any(|n| n.ptr_eq(node))
+	}
+}
+
 pub trait CollectNode<T: Debug + Clone, I: CompareNode<T>> {
 	fn collect_siblings(&self, ident: &I) -> NodeCollection<T>;
 	fn collect_children(&self, ident: &I) -> NodeCollection<T>;
+	fn collect_direct_children(&self, ident: &I) -> NodeCollection<T>;
 	fn collect_linked_list(&self, ident: &I) -> NodeCollection<T>;
+	fn collect_ancestors(&self, ident: &I) -> NodeCollection<T>;
+	fn collect_leaves(&self, ident: &I) -> NodeCollection<T>;
+	fn collect_children_bounded<B: CompareNode<T>>(&self, ident: &I, boundary: &B) -> NodeCollection<T>;
 }                                                         
 
 impl<T: Debug + Clone, I: CompareNode<T>> CollectNode<T, I> for Node<T> {
@@ -480,7 +1294,7 @@ impl<T: Debug + Clone, I: CompareNode<T>> CollectNode<T, I> for Node<T> {
 	
 		let mut collection = Vec::new();
 		
-		if ident.compare(&self) {
+		if ident.compare(self) {
 			collection.push(self.clone());
 		}
 
@@ -533,76 +1347,81 @@ impl<T: Debug + Clone, I: CompareNode<T>> CollectNode<T, I> for Node<T> {
 	}
 
 	/// Given an identifier of type implementing `CompareNode` this iterates over all the nodes that stand 
-	/// lower and deeper in the linked list. Every child satysfying the identifier get collected into a `NodeCollection`
+	/// lower and deeper in the linked list. Every descendant of `&self` satysfying the identifier get collected
+	/// into a `NodeCollection`, in document order ( pre-order ). `&self` is not evaluated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(2, node!(4), node!(5, node!(6))), node!(8), node!(9, node!(10)));
+	///		let contents: Vec<i32> = node.collect_children(&Even).into_iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(contents, vec![2, 4, 6, 8, 10]);
+	///
+	///		// a single level of children.
+	///		let flat = node!(0, node!(2), node!(3), node!(4));
+	///		assert_eq!(flat.collect_children(&Even).into_nodes().len(), 2);
+	///		assert!(node!(2).collect_children(&Even).into_nodes().is_empty());
+	/// }
+	/// ```
 	fn collect_children(&self, ident: &I) -> NodeCollection<T> {
-
 		let mut collection = Vec::new();
+		let mut next = self.child();
 
-		if let Some(child) = self.child() {
-
-			let mut child = child;
-
-			while let Some(c) = child.child() {
-
-				// we reached a new depth-level in hierarchy
-
-				child = c;
-
-				if ident.compare(&child) {
-					collection.push(child.clone());
-				}
-
-				// iterates horizontally in the previous siblings
-				
-				if let Some(prev) = child.prev() {
-					let mut prev = prev;
-
-					/* do */ {
-
-						if ident.compare(&prev) {
-							collection.push(prev.clone());
-						}
-
-						collection.extend(prev.collect_children(ident).nodes);
-
-					} while let Some(p) = prev.prev() {
-						
-						prev = p;
-
-						if ident.compare(&prev) {
-							collection.push(prev.clone());
-						}
-
-						collection.extend(prev.collect_children(ident).nodes);
-					}
-				}
-
-				// iterates horizontally in the next siblings
-
-				if let Some(n) = child.next() {
-
-					let mut next = n;
-
-					/* do */ {
-
-						if ident.compare(&next) {
-							collection.push(next.clone());
-						}
-
-						collection.extend(next.collect_children(ident).nodes);
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				collection.push(node.clone());
+			}
 
-					} while let Some(n) = next.next() {
+			next = next_in_subtree(&node, self);
+		}
 
-						next = n;
+		NodeCollection::<T>::from_vec(collection)
+	}
 
-						if ident.compare(&next) {
-							collection.push(next.clone());
-						}
+	/// Collects the children of `&self` satisfying the identifier, in order, without descending any further.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(2, node!(4)), node!(3), node!(6));
+	///		let contents: Vec<i32> = node.collect_direct_children(&Even).into_iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(contents, vec![2, 6]);
+	/// }
+	/// ```
+	fn collect_direct_children(&self, ident: &I) -> NodeCollection<T> {
+		let mut collection = Vec::new();
+		let mut next = self.child();
 
-						collection.extend(next.collect_children(ident).nodes);
-					}
-				}
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				collection.push(node.clone());
 			}
+
+			next = node.next();
 		}
 
 		NodeCollection::<T>::from_vec(collection)
@@ -739,7 +1558,7 @@ impl<T: Debug + Clone, I: CompareNode<T>> CollectNode<T, I> for Node<T> {
 			// iterates in the previous siblings
 			// iterates in the next siblings
 
-			if ident.compare(&self) {
+			if ident.compare(self) {
 				collection.push(self.clone());
 			}
 
@@ -792,20 +1611,9 @@ impl<T: Debug + Clone, I: CompareNode<T>> CollectNode<T, I> for Node<T> {
 
 		NodeCollection::<T>::from_vec(collection)
 	}
-} 
-
-pub trait FindNode<T: Debug + Clone, I: CompareNode<T>> {
-	fn find_next(&self, ident: &I) -> Option<Node<T>>;
-	fn find_prev(&self, ident: &I) -> Option<Node<T>>;
-	fn find_sibling(&self, ident: &I) -> Option<Node<T>>;
-	fn find_child(&self, ident: &I) -> Option<Node<T>>;
-	fn find_linked_list(&self, ident: &I) -> Option<Node<T>>;
-}                                                         
 
-impl<T: Debug + Clone, I: CompareNode<T>> FindNode<T, I> for Node<T> {
-	/// Get the first `Node` in the linked list, at the same depth-level of `&self` and coming after it,
-	/// matching the identifier.
-	/// This guarantees to actually retrive the closest `Node`.
+	/// Collects every ancestor of `&self` matching the identifier, from the parent up to the top parent.
+	/// e.g all the sections enclosing a node, or the chain of nodes an attribute could be inherited from.
 	///
 	/// # Example
 	///
@@ -813,95 +1621,195 @@ impl<T: Debug + Clone, I: CompareNode<T>> FindNode<T, I> for Node<T> {
 	/// use hedel_rs::prelude::*;
 	/// use hedel_rs::*;
 	///
-	/// pub enum NumIdent {
-	///      Equal(i32),
-	///      BiggerThan(i32),
-	///      SmallerThan(i32)
-	/// }
+	/// pub struct Section;
 	///
-	/// impl CompareNode<i32> for NumIdent {
-	///     fn compare(&self, node: &Node<i32>) -> bool {
-	///         match &self {
-	///           NumIdent::Equal(n) => {
-	///               as_content!(node, |content| {
-	///                  content == *n
-	///               })
-	///            },
-	///           NumIdent::BiggerThan(n) => {
-	///             as_content!(node, |content| {
-	///               content > *n
-	///             })
-	///           },
-	///           NumIdent::SmallerThan(n) => {
-	///             as_content!(node, |content| {
-	///                 content < *n
-	///             })
-	///           }
-	///       }
-	///   }
+	/// impl CompareNode<&'static str> for Section {
+	///		fn compare(&self, node: &Node<&'static str>) -> bool {
+	///			node.get().content.starts_with("section")
+	///		}
 	/// }
 	///
 	/// fn main() {
+	///		let doc = node!("section 1", node!("div", node!("section 1.1", node!("p"))));
+	///		let p = doc.first_leaf().unwrap();
 	///
-	///		let node = node!(33,
-	///			node!(1),
-	///			node!(34),
-	///			node!(66)
-	///		); 
-	///		
-	///		let one = node.child().unwrap();
-	///		assert_eq!(
-	///			one.find_next(&NumIdent::BiggerThan(50)).unwrap().to_content(),
-	///			66
-	///		); 
+	///		let sections: Vec<&str> = p.collect_ancestors(&Section).into_iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(sections, vec!["section 1.1", "section 1"]);
 	/// }
 	/// ```
-	fn find_next(&self, ident: &I) -> Option<Node<T>> {
-		if let Some(next) = self.next() {
-			let mut next = next;
+	fn collect_ancestors(&self, ident: &I) -> NodeCollection<T> {
+		let mut collection = Vec::new();
+		let mut parent = self.parent();
 
-			/* do */ {
+		while let Some(p) = parent {
+			if ident.compare(&p) {
+				collection.push(p.clone());
+			}
 
-				if ident.compare(&next) {
-					return Some(next);
-				}
-				
-			} while let Some(n) = next.next() {
-				next = n;
+			parent = p.parent();
+		}
 
-				if ident.compare(&next) {
-					return Some(next);
-				}
+		NodeCollection::<T>::from_vec(collection)
+	}
+
+	/// Collects the descendants of `&self` without children matching the identifier, in document order.
+	/// The identifier is only evaluated on the leaves, the interior nodes are just walked through.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// pub struct Word;
+	///
+	/// impl CompareNode<&'static str> for Word {
+	///		fn compare(&self, node: &Node<&'static str>) -> bool {
+	///			node.get().content.chars().all(char::is_alphabetic)
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let expr = node!("call", node!("print"), node!("args", node!("x"), node!("+"), node!("y")));
+	///
+	///		let words: Vec<&str> = expr.collect_leaves(&Word).into_iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(words, vec!["print", "x", "y"]);
+	/// }
+	/// ```
+	fn collect_leaves(&self, ident: &I) -> NodeCollection<T> {
+		NodeCollection::<T>::from_vec(self.leaves().filter(|leaf| ident.compare(leaf)).collect())
+	}
+
+	/// Collects the descendants of `&self` matching the identifier in document order, without descending
+	/// into the nodes matching `boundary` ( e.g shadow roots or component hosts ).
+	/// Boundary nodes are evaluated themselves, only their subtrees are skipped.
+	/// A `NodeCollection` can be used as a set of boundary nodes.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// pub struct Tag(&'static str);
+	///
+	/// impl CompareNode<&'static str> for Tag {
+	///		fn compare(&self, node: &Node<&'static str>) -> bool {
+	///			node.get().content == self.0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let app = node!("app",
+	///			node!("div", node!("button")),
+	///			node!("shadow-root", node!("button")),
+	///			node!("button")
+	///		);
+	///
+	///		let buttons = app.collect_children_bounded(&Tag("button"), &Tag("shadow-root"));
+	///		assert_eq!(buttons.as_nodes().len(), 2);
+	///
+	///		let div = app.child().unwrap();
+	///		let bounds = NodeCollection::from_vec(vec![div.clone(), div.next().unwrap()]);
+	///		assert!(app.find_child_bounded(&Tag("button"), &bounds).unwrap().parent().unwrap().ptr_eq(&app));
+	/// }
+	/// ```
+	fn collect_children_bounded<B: CompareNode<T>>(&self, ident: &I, boundary: &B) -> NodeCollection<T> {
+		let mut collection = Vec::new();
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				collection.push(node.clone());
 			}
+
+			next = if boundary.compare(&node) {
+				skip_in_subtree(&node, self)
+			} else {
+				next_in_subtree(&node, self)
+			};
 		}
-	
-		None
+
+		NodeCollection::<T>::from_vec(collection)
+	}
+} 
+
+pub trait FindNode<T: Debug + Clone, I: CompareNode<T>> {
+	fn find_next(&self, ident: &I) -> Option<Node<T>>;
+	fn find_prev(&self, ident: &I) -> Option<Node<T>>;
+	fn find_sibling(&self, ident: &I) -> Option<Node<T>>;
+	fn find_child(&self, ident: &I) -> Option<Node<T>>;
+	fn find_linked_list(&self, ident: &I) -> Option<Node<T>>;
+	fn find_nth_child(&self, ident: &I, k: usize) -> Option<Node<T>>;
+	fn find_nth_next(&self, ident: &I, k: usize) -> Option<Node<T>>;
+	fn find_child_bounded<B: CompareNode<T>>(&self, ident: &I, boundary: &B) -> Option<Node<T>>;
+	fn find_sibling_shallow(&self, ident: &I, include_self: bool) -> Option<Node<T>>;
+	fn find_in_sibling_subtrees(&self, ident: &I) -> Option<Node<T>>;
+}                                                         
+
+impl<T: Debug + Clone, I: CompareNode<T>> FindNode<T, I> for Node<T> {
+	/// Get the first `Node` in the linked list, at the same depth-level of `&self` and coming after it,
+	/// matching the identifier.
+	/// This guarantees to actually retrive the closest `Node`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// pub enum NumIdent {
+	///      Equal(i32),
+	///      BiggerThan(i32),
+	///      SmallerThan(i32)
+	/// }
+	///
+	/// impl CompareNode<i32> for NumIdent {
+	///     fn compare(&self, node: &Node<i32>) -> bool {
+	///         match &self {
+	///           NumIdent::Equal(n) => {
+	///               as_content!(node, |content| {
+	///                  content == *n
+	///               })
+	///            },
+	///           NumIdent::BiggerThan(n) => {
+	///             as_content!(node, |content| {
+	///               content > *n
+	///             })
+	///           },
+	///           NumIdent::SmallerThan(n) => {
+	///             as_content!(node, |content| {
+	///                 content < *n
+	///             })
+	///           }
+	///       }
+	///   }
+	/// }
+	///
+	/// fn main() {
+	///
+	///		let node = node!(33,
+	///			node!(1),
+	///			node!(34),
+	///			node!(66)
+	///		); 
+	///		
+	///		let one = node.child().unwrap();
+	///		assert_eq!(
+	///			one.find_next(&NumIdent::BiggerThan(50)).unwrap().to_content(),
+	///			66
+	///		); 
+	/// }
+	/// ```
+	fn find_next(&self, ident: &I) -> Option<Node<T>> {
+		self.following_siblings().find(|next| ident.compare(next))
 	}
 	
 	/// Get the first `Node` in the linked list, at the same depth-level of `&self` and coming before it,
 	/// matching the identifier.
 	/// This guarantees to actually retrive the closest `Node`.
 	fn find_prev(&self, ident: &I) -> Option<Node<T>> {
-		if let Some(prev) = self.prev() {
-			let mut prev = prev;
-
-			/* do */ {
-
-				if ident.compare(&prev) {
-					return Some(prev);
-				}
-				
-			} while let Some(n) = prev.prev() {
-				prev = n;
-
-				if ident.compare(&prev) {
-					return Some(prev);
-				}
-	
-			}
-		}
-		None
-
+		self.preceding_siblings().find(|prev| ident.compare(prev))
 	}
 	
 	/// Get a `Node` somewhere in the linked list matching the identifier.
@@ -982,7 +1890,7 @@ impl<T: Debug + Clone, I: CompareNode<T>> FindNode<T, I> for Node<T> {
 
 		} else {
 
-			if ident.compare(&self) {
+			if ident.compare(self) {
 				return Some(self.clone());
 			}
 
@@ -1120,9 +2028,104 @@ impl<T: Debug + Clone, I: CompareNode<T>> FindNode<T, I> for Node<T> {
 		None
 	}
 
+	/// Get the `k`-th descendant of `&self` in document order matching the identifier, counting from `1`.
+	/// The first `k - 1` matches are skipped while walking, without collecting them.
+	/// Returns `None` if there are less than `k` matches, or if `k` is `0`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// pub struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(4)), node!(5), node!(6), node!(8));
+	///
+	///		assert_eq!(node.find_nth_child(&Even, 2).unwrap().to_content(), 4);
+	///		assert!(node.find_nth_child(&Even, 5).is_none());
+	///
+	///		let two = node.child().unwrap();
+	///		assert_eq!(two.find_nth_next(&Even, 2).unwrap().to_content(), 8);
+	/// }
+	/// ```
+	fn find_nth_child(&self, ident: &I, k: usize) -> Option<Node<T>> {
+		let mut left = k;
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				left = left.checked_sub(1)?;
+
+				if left == 0 {
+					return Some(node);
+				}
+			}
+
+			next = next_in_subtree(&node, self);
+		}
+
+		None
+	}
+
+	/// Get the `k`-th `Node` at the same depth-level of `&self` and coming after it,
+	/// matching the identifier, counting from `1`.
+	/// Returns `None` if there are less than `k` matches, or if `k` is `0`.
+	fn find_nth_next(&self, ident: &I, k: usize) -> Option<Node<T>> {
+		let mut left = k;
+		let mut next = self.next();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				left = left.checked_sub(1)?;
+
+				if left == 0 {
+					return Some(node);
+				}
+			}
+
+			next = node.next();
+		}
+
+		None
+	}
+
+	/// Get the first descendant of `&self` in document order matching the identifier, without descending
+	/// into the nodes matching `boundary`. Boundary nodes are evaluated themselves, only their subtrees are skipped.
+	/// Refer to `CollectNode::collect_children_bounded` for an example.
+	fn find_child_bounded<B: CompareNode<T>>(&self, ident: &I, boundary: &B) -> Option<Node<T>> {
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				return Some(node);
+			}
+
+			next = if boundary.compare(&node) {
+				skip_in_subtree(&node, self)
+			} else {
+				next_in_subtree(&node, self)
+			};
+		}
+
+		None
+	}
+
 	/// In the case you can't know if the `Node` you are looking for comes before or after, here's a combination of the two previous methods. 
 	/// Always prefer using `HedelFind::find_next` and `HedelFind::find_prev` when you know the position of the `Node`,
 	/// as they might be faster.
+	///
+	/// NOTE: `&self` is never evaluated, and every sibling is evaluated along with its whole subtree:
+	/// the previous siblings come first, from the closest one, then the next ones.
+	/// Use `FindNode::find_sibling_shallow` to only search the same depth-level,
+	/// and `FindNode::find_in_sibling_subtrees` to only search below it.
 	fn find_sibling(&self, ident: &I) -> Option<Node<T>> {
 		// in case we dont have a parent
 		// iterates in the previous siblings
@@ -1187,12 +2190,105 @@ impl<T: Debug + Clone, I: CompareNode<T>> FindNode<T, I> for Node<T> {
 		None
 	}
 
+	/// Get the first `Node` at the same depth-level of `&self` matching the identifier, without descending
+	/// into any subtree. `&self` is evaluated first if `include_self` is `true`, then the previous siblings
+	/// from the closest one, then the next ones, like `FindNode::find_sibling`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1, node!(2)), node!(4), node!(6));
+	///		let four = node.child().unwrap().next().unwrap();
+	///
+	///		// the children of the siblings are not evaluated.
+	///		assert_eq!(four.find_sibling_shallow(&Even, false).unwrap().to_content(), 6);
+	///		assert!(four.find_sibling_shallow(&Even, true).unwrap().ptr_eq(&four));
+	///		assert_eq!(four.find_sibling(&Even).unwrap().to_content(), 2);
+	/// }
+	/// ```
+	fn find_sibling_shallow(&self, ident: &I, include_self: bool) -> Option<Node<T>> {
+		if include_self && ident.compare(self) {
+			return Some(self.clone());
+		}
+
+		if let Some(prev) = self.find_prev(ident) {
+			return Some(prev);
+		}
+
+		self.find_next(ident)
+	}
+
+	/// Get the first descendant of the siblings of `&self` matching the identifier, in the same order
+	/// as `FindNode::find_sibling`. Neither `&self`, its subtree nor the siblings themselves are evaluated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1, node!(3)), node!(5, node!(6)), node!(4, node!(8)));
+	///		let five = node.child().unwrap().next().unwrap();
+	///
+	///		assert_eq!(five.find_in_sibling_subtrees(&Even).unwrap().to_content(), 8);
+	/// }
+	/// ```
+	fn find_in_sibling_subtrees(&self, ident: &I) -> Option<Node<T>> {
+		let mut prev = self.prev();
+
+		while let Some(node) = prev {
+			if let Some(found) = node.find_child(ident) {
+				return Some(found);
+			}
+
+			prev = node.prev();
+		}
+
+		let mut next = self.next();
+
+		while let Some(node) = next {
+			if let Some(found) = node.find_child(ident) {
+				return Some(found);
+			}
+
+			next = node.next();
+		}
+
+		None
+	}
 }
 
 pub trait GetNode<T: Debug + Clone> {
 	fn get_first_sibling(&self) -> Option<Node<T>>;
 	fn get_last_sibling(&self) -> Option<Node<T>>;
 	fn get_last_child(&self) -> Option<Node<T>>;
+	fn get_first_child(&self) -> Option<Node<T>>;
+	fn get_sibling(&self, n: usize) -> Option<Node<T>>;
+	fn first_leaf(&self) -> Option<Node<T>>;
+	fn last_leaf(&self) -> Option<Node<T>>;
+	fn next_in_document(&self) -> Option<Node<T>>;
+	fn prev_in_document(&self) -> Option<Node<T>>;
 }
 
 impl<T: Debug + Clone> GetNode<T> for Node<T> {
@@ -1257,12 +2353,157 @@ impl<T: Debug + Clone> GetNode<T> for Node<T> {
 
 		} None
 	}
+
+	/// Get the first child `Node` of `&self`, same as `Node::child`.
+	/// If None is returned, `&self` doesn't have any children.
+	fn get_first_child(&self) -> Option<Node<T>> {
+		self.child()
+	}
+
+	/// Get the `n`-th `Node` in the linked list at the same depth level of `&self`,
+	/// counting from the first one ( which is the `0`-th ), `&self` included.
+	/// If None is returned, there are less than `n + 1` nodes at that depth level.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1), node!(2), node!(3));
+	///		let three = node.get_last_child().unwrap();
+	///
+	///		assert_eq!(three.get_sibling(0).unwrap().to_content(), 1);
+	///		assert!(three.get_sibling(2).unwrap().ptr_eq(&three));
+	///		assert!(three.get_sibling(3).is_none());
+	///		assert_eq!(node.get_first_child().unwrap().to_content(), 1);
+	/// }
+	/// ```
+	fn get_sibling(&self, n: usize) -> Option<Node<T>> {
+		let mut sibling = self.get_first_sibling().unwrap_or_else(|| self.clone());
+
+		for _ in 0..n {
+			sibling = sibling.next()?;
+		}
+
+		Some(sibling)
+	}
+
+	/// Get the deepest left-most descendant of `&self`, following the first child at every depth-level.
+	/// If None is returned, `&self` doesn't have any children.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(3), node!(4)), node!(5, node!(6)));
+	///		assert_eq!(node.first_leaf().unwrap().to_content(), 3);
+	///		assert_eq!(node.last_leaf().unwrap().to_content(), 6);
+	/// }
+	/// ```
+	fn first_leaf(&self) -> Option<Node<T>> {
+		let mut leaf = self.child()?;
+
+		while let Some(child) = leaf.child() {
+			leaf = child;
+		}
+
+		Some(leaf)
+	}
+
+	/// Get the deepest right-most descendant of `&self`, following the last child at every depth-level.
+	/// If None is returned, `&self` doesn't have any children.
+	fn last_leaf(&self) -> Option<Node<T>> {
+		let mut leaf = self.get_last_child()?;
+
+		while let Some(child) = leaf.get_last_child() {
+			leaf = child;
+		}
+
+		Some(leaf)
+	}
+
+	/// Get the `Node` coming right after `&self` in document order ( pre-order ):
+	/// its first child, otherwise its next sibling, otherwise the next sibling of the closest ancestor having one.
+	/// Root-level siblings are part of the document. If None is returned, `&self` is the last `Node`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2, node!(3))), node!(4));
+	///		let three = list.first().unwrap().first_leaf().unwrap();
+	///
+	///		assert_eq!(three.next_in_document().unwrap().to_content(), 4);
+	///		assert_eq!(three.prev_in_document().unwrap().to_content(), 2);
+	///		assert_eq!(list.first().unwrap().next_in_document().unwrap().to_content(), 2);
+	/// }
+	/// ```
+	fn next_in_document(&self) -> Option<Node<T>> {
+		if let Some(child) = self.child() {
+			return Some(child);
+		}
+
+		let mut current = self.clone();
+
+		loop {
+			if let Some(next) = current.next() {
+				return Some(next);
+			}
+
+			current = current.parent()?;
+		}
+	}
+
+	/// Get the `Node` coming right before `&self` in document order ( pre-order ):
+	/// the last leaf of its previous sibling, otherwise its previous sibling, otherwise its parent.
+	/// If None is returned, `&self` is the first `Node`.
+	fn prev_in_document(&self) -> Option<Node<T>> {
+		if let Some(prev) = self.prev() {
+			return Some(prev.last_leaf().unwrap_or(prev));
+		}
+
+		self.parent()
+	}
 }
 
 pub trait AppendNode<T: Debug + Clone> {
 	fn append_next(&self, node: Node<T>);
 	fn append_child(&self, node: Node<T>);
 	fn append_prev(&self, node: Node<T>);
+	fn splice_after(&self, list: List<T>);
+	fn splice_before(&self, list: List<T>);
+	fn append_children<I: IntoIterator<Item = Node<T>>>(&self, nodes: I);
+	fn prepend_child(&self, node: Node<T>);
+}
+
+/// Empties `list` and returns its first and last root-level nodes, re-parented under `parent`.
+/// Only the root-level nodes get visited, their subtrees are moved as they are.
+fn take_root_chain<T: Debug + Clone>(list: &List<T>, parent: Option<WeakNode<T>>) -> Option<(Node<T>, Node<T>)> {
+	let first = list.take_roots()?;
+	list.bump_generation();
+
+	let mut last = first.clone();
+
+	/* do */ {
+		let mut borrow = last.get_mut();
+		borrow.parent = parent.clone();
+		borrow.list = None;
+	} while let Some(next) = last.next() {
+		last = next;
+		let mut borrow = last.get_mut();
+		borrow.parent = parent.clone();
+		borrow.list = None;
+	}
+
+	Some((first, last))
 }
 
 impl<T: Debug + Clone> AppendNode<T> for Node<T> {
@@ -1283,9 +2524,10 @@ impl<T: Debug + Clone> AppendNode<T> for Node<T> {
 	/// }	
 	/// ```
 	fn append_next(&self, node: Node<T>) {
-		if let Some(parent) = self.parent() {
-			node.get_mut().parent = Some(parent.downgrade());
-		}
+		self.check_mutable();
+		node.check_mutable();
+
+		node.get_mut().parent = self.get().parent.clone();
 		
 		if let Some(next) = self.next() {
 			next.get_mut().prev = Some(node.downgrade());
@@ -1294,6 +2536,13 @@ impl<T: Debug + Clone> AppendNode<T> for Node<T> {
 
 		self.get_mut().next = Some(node.clone());
 		node.get_mut().prev = Some(self.downgrade());
+
+		if let Some(list) = self.own_list() {
+			list.adopt_roots(&node, &node);
+		}
+
+		self.touch();
+		record(Change::Insertion(&node, &node));
 	}
 	
 	/// Inserts a new node right before `&self`.
@@ -1312,32 +2561,26 @@ impl<T: Debug + Clone> AppendNode<T> for Node<T> {
 	/// }
 	/// ```
 	fn append_prev(&self, node: Node<T>) {
-		
-		
-		
-		
+		self.check_mutable();
+		node.check_mutable();
+
 		if let Some(prev) = self.prev() {
 			prev.get_mut().next = Some(node.clone());
 			node.get_mut().prev = Some(prev.downgrade());
-			self.get_mut().prev = Some(node.downgrade());
-			node.get_mut().next = Some(self.clone());
-
+		} else if let Some(parent) = self.parent() {
+			parent.get_mut().child = Some(node.clone());
+		}
 
-		} else {
-			if let Some(list) = self.list() {
+		self.get_mut().prev = Some(node.downgrade());
+		node.get_mut().next = Some(self.clone());
+		node.get_mut().parent = self.get().parent.clone();
 
-				self.get_mut().prev = Some(node.downgrade());
-				node.get_mut().next = Some(self.clone());
-				node.get_mut().list = Some(list.downgrade());	
-				*list.first.get_mut() = Some(node.clone());
-				
-			} else { /* !!!!HELP */ } 
+		if let Some(list) = self.own_list() {
+			list.adopt_roots(&node, &node);
 		}
-		
-		if let Some(parent) = self.parent() {
-			node.get_mut().parent = Some(parent.downgrade());
-			parent.get_mut().child = Some(node.clone());
-		}	
+
+		self.touch();
+		record(Change::Insertion(&node, &node));
 	}
 
 	/// Inserts a new node right after the last child of `&self`.
@@ -1355,18 +2598,206 @@ impl<T: Debug + Clone> AppendNode<T> for Node<T> {
 	/// }
 	/// ```
 	fn append_child(&self, node: Node<T>) {
+		self.check_mutable();
+		node.check_mutable();
+
 		node.get_mut().parent = Some(self.downgrade());
 		if let Some(last_child) = self.get_last_child() {
 			last_child.get_mut().next = Some(node.clone());
 			node.get_mut().prev = Some(last_child.downgrade());
 		} else {
-			self.get_mut().child = Some(node);
+			self.get_mut().child = Some(node.clone());
+		}
+
+		self.touch();
+		record(Change::Insertion(&node, &node));
+	}
+
+	/// Inserts a new node before the first child of `&self`, in O(1).
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(3));
+	///		node.prepend_child(node!(2));
+	///
+	///		assert_eq!(node.child().unwrap().to_content(), 2);
+	///		assert_eq!(node.child().unwrap().next().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	fn prepend_child(&self, node: Node<T>) {
+		self.check_mutable();
+		node.check_mutable();
+
+		node.get_mut().parent = Some(self.downgrade());
+
+		if let Some(first_child) = self.child() {
+			first_child.get_mut().prev = Some(node.downgrade());
+			node.get_mut().next = Some(first_child);
+		}
+
+		self.get_mut().child = Some(node.clone());
+
+		self.touch();
+		record(Change::Insertion(&node, &node));
+	}
+
+	/// Inserts all the given nodes after the last child of `&self`, in order.
+	/// The new nodes get linked among themselves first and attached in one pass,
+	/// so the last child is only looked up once.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1));
+	///		node.append_children((2..5).map(|n| node!(n)));
+	///
+	///		assert_eq!(node.get_last_child().unwrap().to_content(), 4);
+	///		assert_eq!(node.get_last_child().unwrap().parent().unwrap().to_content(), 0);
+	/// }
+	/// ```
+	fn append_children<I: IntoIterator<Item = Node<T>>>(&self, nodes: I) {
+		self.check_mutable();
+
+		let nodes: Vec<Node<T>> = nodes.into_iter().collect();
+
+		for node in nodes.iter() {
+			node.check_mutable();
+		}
+
+		let mut first: Option<Node<T>> = None;
+		let mut last: Option<Node<T>> = None;
+
+		for node in nodes {
+			{
+				let mut borrow = node.get_mut();
+				borrow.parent = Some(self.downgrade());
+				borrow.prev = last.as_ref().map(|l| l.downgrade());
+				borrow.next = None;
+				borrow.list = None;
+			}
+
+			match last {
+				Some(ref l) => l.get_mut().next = Some(node.clone()),
+				None => first = Some(node.clone())
+			}
+
+			last = Some(node);
+		}
+
+		if let (Some(first), Some(last)) = (first, last) {
+			if let Some(last_child) = self.get_last_child() {
+				first.get_mut().prev = Some(last_child.downgrade());
+				last_child.get_mut().next = Some(first.clone());
+			} else {
+				self.get_mut().child = Some(first.clone());
+			}
+
+			self.touch();
+			record(Change::Insertion(&first, &last));
+		}
+	}
+
+	/// Moves all the root-level nodes of `list` right after `&self`, keeping their order
+	/// and their subtrees. Only the two boundaries get re-linked, `list` is left empty.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2), node!(5));
+	///		let two = node.child().unwrap();
+	///
+	///		two.splice_after(list!(node!(3), node!(4)));
+	///
+	///		assert_eq!(two.next().unwrap().to_content(), 3);
+	///		assert_eq!(node.get_last_child().unwrap().prev().unwrap().to_content(), 4);
+	///		assert_eq!(two.next().unwrap().parent().unwrap().to_content(), 1);
+	/// }
+	/// ```
+	fn splice_after(&self, list: List<T>) {
+		self.check_mutable();
+
+		let parent = self.get().parent.clone();
+
+		if let Some((first, last)) = take_root_chain(&list, parent) {
+			if let Some(next) = self.next() {
+				next.get_mut().prev = Some(last.downgrade());
+				last.get_mut().next = Some(next);
+			}
+
+			first.get_mut().prev = Some(self.downgrade());
+			self.get_mut().next = Some(first.clone());
+
+			if let Some(own) = self.own_list() {
+				own.adopt_roots(&first, &last);
+			}
+
+			self.touch();
+			record(Change::Insertion(&first, &last));
+		}
+	}
+
+	/// Moves all the root-level nodes of `list` right before `&self`, keeping their order
+	/// and their subtrees. Only the two boundaries get re-linked, `list` is left empty.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(4));
+	///		let four = node.child().unwrap();
+	///
+	///		four.splice_before(list!(node!(2), node!(3)));
+	///
+	///		assert_eq!(node.child().unwrap().to_content(), 2);
+	///		assert_eq!(four.prev().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	fn splice_before(&self, list: List<T>) {
+		self.check_mutable();
+
+		let parent = self.get().parent.clone();
+
+		if let Some((first, last)) = take_root_chain(&list, parent) {
+			if let Some(prev) = self.prev() {
+				prev.get_mut().next = Some(first.clone());
+				first.get_mut().prev = Some(prev.downgrade());
+			} else if let Some(parent) = self.parent() {
+				parent.get_mut().child = Some(first.clone());
+			}
+
+			self.get_mut().prev = Some(last.downgrade());
+			last.get_mut().next = Some(self.clone());
+
+			if let Some(own) = self.own_list() {
+				own.adopt_roots(&first, &last);
+			}
+
+			self.touch();
+			record(Change::Insertion(&first, &last));
 		}
 	}
 }
 pub trait InsertNode<T: Debug + Clone> {
 	fn insert_sibling(&self, position: usize, node: Node<T>);
 	fn insert_child(&self, position: usize, node: Node<T>);
+	fn insert_child_before(&self, child: &Node<T>, node: Node<T>);
+	fn insert_child_after(&self, child: &Node<T>, node: Node<T>);
 }
 
 impl<T: Debug + Clone> InsertNode<T> for Node<T> {
@@ -1389,8 +2820,6 @@ impl<T: Debug + Clone> InsertNode<T> for Node<T> {
 	///		println!("{}", three.to_content()); // prints 3
 	/// }
 	/// ```
-	///
-	
 	fn insert_sibling(&self, position: usize, node: Node<T>) {
 		
 		let mut sibling = self.clone(); 
@@ -1407,35 +2836,11 @@ impl<T: Debug + Clone> InsertNode<T> for Node<T> {
 			}	
 		} 
 		
-		// PARENT
-		//  node 0 -> next: my OK
-		//  node 1 -> prev: my
-		//  node 2
-		//  
-		// my -> next: node 1
-		// my -> prev: node 0
-		// my -> parent: ---    OK
-
 		if c != position {
 			// append to the last
-			sibling.append_next(node.clone());
+			sibling.append_next(node);
 		} else {
-			
-			if let Some(parent) = self.parent() {
-				node.get_mut().parent = Some(parent.downgrade());
-			}
-
-			if let Some(prev) = sibling.prev() {
-				let previous = prev;
-				previous.get_mut().next = Some(node.clone());
-			} else {
-				if let Some(parent) = self.parent() {
-					// NOTE: NOT SUPPORTING NODELIST, BUG
-					parent.get_mut().child = Some(node.clone());
-				}	
-			}
-
-			sibling.get_mut().prev = Some(node.downgrade());
+			sibling.append_prev(node);
 		}
 	}
 
@@ -1456,18 +2861,73 @@ impl<T: Debug + Clone> InsertNode<T> for Node<T> {
 	///		println!("{}", three.to_content()); // prints 3
 	/// }
 	/// ```
-	///
-	
 	fn insert_child(&self, position: usize, node: Node<T>) {
+		self.check_mutable();
+		node.check_mutable();
+
 		if let Some(first_child) = self.child() {
 			first_child.insert_sibling(position, node);
 		} else {
 			node.get_mut().parent = Some(self.downgrade());
-			self.get_mut().child = Some(node);
+			self.get_mut().child = Some(node.clone());
+
+			self.touch();
+			record(Change::Insertion(&node, &node));
 		}
 	}	
+
+	/// Inserts a new node among the children of `&self`, right before `child`.
+	/// Unlike `InsertNode::insert_child` it doesn't walk the children to find the position.
+	///
+	/// Panics if `child` is not a child of `&self`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2), node!(4));
+	///		let four = node.get_last_child().unwrap();
+	///
+	///		node.insert_child_before(&four, node!(3));
+	///		assert_eq!(four.prev().unwrap().to_content(), 3);
+	///		assert_eq!(node.child().unwrap().next().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	fn insert_child_before(&self, child: &Node<T>, node: Node<T>) {
+		assert!(child.parent().is_some_and(|p| p.ptr_eq(self)), "`child` is not a child of `&self`");
+		child.append_prev(node);
+	}
+
+	/// Inserts a new node among the children of `&self`, right after `child`.
+	/// Unlike `InsertNode::insert_child` it doesn't walk the children to find the position.
+	///
+	/// Panics if `child` is not a child of `&self`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2), node!(4));
+	///		let two = node.child().unwrap();
+	///
+	///		node.insert_child_after(&two, node!(3));
+	///		assert_eq!(two.next().unwrap().to_content(), 3);
+	///		assert_eq!(node.get_last_child().unwrap().prev().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	fn insert_child_after(&self, child: &Node<T>, node: Node<T>) {
+		assert!(child.parent().is_some_and(|p| p.ptr_eq(self)), "`child` is not a child of `&self`");
+		child.append_next(node);
+	}
 }
 /// Generate a node blazingly fast, with any number of child nodes.
+/// Without arguments, it builds a node with the default content.
 /// 
 /// # Example
 ///
@@ -1482,10 +2942,14 @@ impl<T: Debug + Clone> InsertNode<T> for Node<T> {
 ///		);
 ///
 ///		let another_node = node!("Another");
+///		let placeholder: Node<String> = node!();
 /// }
 /// ```
 #[macro_export]
 macro_rules! node {
+	() => {
+		<hedel_rs::Node<_> as Default>::default()
+	};
 	($content: expr $(,$node: expr)*) => {
 		{
 			let mut node = hedel_rs::Node::new($content);
@@ -1558,10 +3022,12 @@ macro_rules! node {
 				/* do */ {
 
 					child.get_mut().parent = Some(node.downgrade());
+					child.get_mut().list = None;
 
 				} while let Some(ch) = child.next() {
 					child = ch;
 					child.get_mut().parent = Some(node.downgrade());
+					child.get_mut().list = None;
 				}
 			}
 
@@ -0,0 +1,305 @@
+use std::fmt::Debug;
+
+#[cfg(feature = "serde")]
+use serde::{
+	Serialize,
+	Deserialize
+};
+
+use crate::{
+	node::{
+		Node,
+		AppendNode,
+		GetNode
+	},
+	list::{
+		List,
+		observed
+	},
+	path::{
+		NodePath,
+		PathNode
+	},
+	builder::TreeBuilder,
+	iter::next_in_subtree,
+	range::{
+		unlink_run,
+		nth_child,
+		child_count,
+		RunIter
+	},
+	errors::HedelError,
+	watch::TreeEvent,
+};
+
+/// A structural operation performed on a linked list, recorded by `List::start_recording`.
+/// Nodes are addressed by their `NodePath` at the time of the operation, so a sequence of
+/// operations can be replayed on a copy of the original linked list with `List::apply_ops`.
+/// With the `serde` feature it implements `Serialize` and `Deserialize`.
+///
+/// Every change goes through two primitives: moving nodes around records a `Remove` followed by an `Insert`.
+/// Changes to the contents are not structural, and aren't recorded.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TreeOp<T> {
+	/// Inserts a run of siblings, the first one ending up at `path`. `nodes` holds every subtree
+	/// of the run in document order, as `(content, number of children)` pairs.
+	Insert { path: NodePath, nodes: Vec<(T, usize)> },
+	/// Removes `count` siblings, starting from the one at `path`, along with their subtrees.
+	Remove { path: NodePath, count: usize }
+}
+
+/// A structural change reported to `record`.
+pub(crate) enum Change<'a, T: Debug + Clone> {
+	/// The run of siblings from the first node to the last one got linked.
+	Insertion(&'a Node<T>, &'a Node<T>),
+	/// The run of siblings from the first node to the last one is about to be unlinked.
+	Removal(&'a Node<T>, &'a Node<T>)
+}
+
+/// Encodes the subtrees of the run of siblings from `first` to `last`, refer to `TreeOp::Insert`.
+fn encode<T: Debug + Clone>(first: &Node<T>, last: &Node<T>) -> Vec<(T, usize)> {
+	let mut nodes = Vec::new();
+
+	for root in RunIter::new(first, last) {
+		let mut next = Some(root.clone());
+
+		while let Some(node) = next {
+			nodes.push((node.get().content.clone(), child_count(&node)));
+			next = next_in_subtree(&node, &root);
+		}
+	}
+
+	nodes
+}
+
+/// Records the change if the linked list the changed nodes belong to is recording, and notifies its watchers.
+/// The linked list is only looked up if some list on the thread is observed, and the contents
+/// only get cloned when recording: the watchers get the path and the number of siblings.
+pub(crate) fn record<T: Debug + Clone>(change: Change<'_, T>) {
+	if !observed() {
+		return;
+	}
+
+	let (first, last) = match change {
+		Change::Insertion(first, last) | Change::Removal(first, last) => (first, last)
+	};
+
+	let list = match first.root_list() {
+		Some(list) => list,
+		None => return
+	};
+
+	let recording = list.ops.get().is_some();
+	let watched = !list.watchers.get().is_empty();
+
+	if !recording && !watched {
+		return;
+	}
+
+	let path = first.path();
+	let count = RunIter::new(first, last).count();
+
+	if watched {
+		let event = match change {
+			Change::Insertion(..) => TreeEvent::Inserted { path: path.clone(), count },
+			Change::Removal(..) => TreeEvent::Removed { path: path.clone(), count }
+		};
+
+		// the receivers dropped in the meantime stop being notified.
+		list.watchers.get_mut().retain(|watcher| watcher.send(event.clone()).is_ok());
+	}
+
+	if recording {
+		let op = match change {
+			Change::Insertion(..) => TreeOp::Insert { path, nodes: encode(first, last) },
+			Change::Removal(..) => TreeOp::Remove { path, count }
+		};
+
+		if let Some(ops) = list.ops.get_mut().as_mut() {
+			ops.push(op);
+		}
+	}
+}
+
+/// Rebuilds the run of subtrees encoded in a `TreeOp::Insert`.
+fn decode<T: Debug + Clone>(nodes: &[(T, usize)]) -> Result<List<T>, HedelError> {
+	let mut builder = TreeBuilder::new();
+	// the number of children still expected by every open node.
+	let mut expected: Vec<usize> = Vec::new();
+
+	for (content, children) in nodes {
+		if let Some(last) = expected.last_mut() {
+			*last -= 1;
+		}
+
+		builder.start_node(content.clone());
+		expected.push(*children);
+
+		while expected.last() == Some(&0) {
+			expected.pop();
+			builder.end_node();
+		}
+	}
+
+	if !expected.is_empty() {
+		return Err(HedelError::Encoding("a node of the inserted run misses some children".to_string()));
+	}
+
+	Ok(builder.finish())
+}
+
+fn invalid(path: &NodePath) -> HedelError {
+	HedelError::InvalidPath(path.to_string())
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Starts recording every structural operation performed on the linked list as a `TreeOp`,
+	/// discarding the operations recorded so far.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2)), node!(3));
+	///		let replica = list!(node!(1, node!(2)), node!(3));
+	///
+	///		list.start_recording();
+	///
+	///		let one = list.first().unwrap();
+	///		one.append_child(node!(4, node!(5)));
+	///		one.child().unwrap().detach();
+	///		one.next().unwrap().append_next(node!(6));
+	///
+	///		let ops = list.stop_recording();
+	///		assert_eq!(ops.len(), 3);
+	///
+	///		replica.apply_ops(&ops).unwrap();
+	///
+	///		let first = replica.first().unwrap();
+	///		assert_eq!(first.child().unwrap().child().unwrap().to_content(), 5);
+	///		assert_eq!(first.get_last_sibling().unwrap().to_content(), 6);
+	/// }
+	/// ```
+	pub fn start_recording(&self) {
+		self.observe();
+		*self.ops.get_mut() = Some(Vec::new());
+	}
+
+	/// Stops recording and returns the recorded operations.
+	pub fn stop_recording(&self) -> Vec<TreeOp<T>> {
+		self.ops.get_mut().take().unwrap_or_default()
+	}
+
+	/// Returns the operations recorded so far, while recording continues.
+	pub fn take_ops(&self) -> Vec<TreeOp<T>> {
+		self.ops.get_mut().as_mut().map(std::mem::take).unwrap_or_default()
+	}
+
+	/// Returns `true` if the linked list is recording its structural operations.
+	pub fn is_recording(&self) -> bool {
+		self.ops.get().is_some()
+	}
+
+	/// Moves the root-level nodes of `run` after the children of `parent`,
+	/// or at the end of the root-level if `parent` is `None`.
+	fn append_run(&self, parent: Option<Node<T>>, run: List<T>) {
+		let last = match parent {
+			Some(ref parent) => parent.get_last_child(),
+			None => self.last()
+		};
+
+		if let Some(last) = last {
+			last.splice_after(run);
+			return;
+		}
+
+		let first = match run.take_roots() {
+			Some(first) => first,
+			None => return
+		};
+
+		let last = first.get_last_sibling().unwrap_or_else(|| first.clone());
+
+		match parent {
+			Some(parent) => parent.append_children(RunIter::new(&first, &last)),
+			None => {
+				self.adopt_roots(&first, &last);
+				self.bump_generation();
+				record(Change::Insertion(&first, &last));
+			}
+		}
+	}
+
+	/// Get the node at the given path, if any.
+	pub fn node_at(&self, path: &NodePath) -> Option<Node<T>> {
+		let (first, rest) = path.indexes.split_first()?;
+		let mut node = self.first()?.get_sibling(*first)?;
+
+		for index in rest {
+			node = nth_child(&node, *index)?;
+		}
+
+		Some(node)
+	}
+
+	/// Replays the given operations, in order, on the linked list. Applied to a copy of the linked list
+	/// the operations were recorded on, it reproduces the same structure.
+	///
+	/// Returns `HedelError::InvalidPath` if an operation points to a position which doesn't exist,
+	/// or `HedelError::Encoding` if an inserted run is malformed. The operations before it stay applied.
+	/// The error is wrapped in `HedelError::Context`, naming the failed operation ( `"insert"` or `"remove"` )
+	/// and its path.
+	pub fn apply_ops(&self, ops: &[TreeOp<T>]) -> Result<(), HedelError> {
+		for op in ops {
+			let (operation, path) = match op {
+				TreeOp::Insert { path, .. } => ("insert", path),
+				TreeOp::Remove { path, .. } => ("remove", path)
+			};
+
+			self.apply_op(op).map_err(|err| err.context(operation, Some(path.clone())))?;
+		}
+
+		Ok(())
+	}
+
+	fn apply_op(&self, op: &TreeOp<T>) -> Result<(), HedelError> {
+		match op {
+			TreeOp::Insert { path, nodes } => {
+				let run = decode(nodes)?;
+				let (index, parent_path) = path.indexes.split_last().ok_or_else(|| invalid(path))?;
+
+				let parent = match parent_path.is_empty() {
+					true => None,
+					false => Some(self.node_at(&NodePath::new(parent_path.to_vec())).ok_or_else(|| invalid(path))?)
+				};
+
+				let (at, count) = match parent {
+					Some(ref parent) => (nth_child(parent, *index), child_count(parent)),
+					None => (self.first().and_then(|f| f.get_sibling(*index)), self.root_count())
+				};
+
+				match at {
+					Some(at) => at.splice_before(run),
+					None if *index == count => self.append_run(parent, run),
+					None => return Err(invalid(path))
+				}
+			},
+			TreeOp::Remove { path, count } => {
+				let first = self.node_at(path).ok_or_else(|| invalid(path))?;
+				let mut last = first.clone();
+
+				for _ in 1..*count {
+					last = last.next().ok_or_else(|| invalid(path))?;
+				}
+
+				unlink_run(&first, &last);
+			}
+		}
+
+		Ok(())
+	}
+}
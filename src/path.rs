@@ -0,0 +1,277 @@
+use std::{
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+		Display
+	},
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+	Serialize,
+	Deserialize
+};
+
+use crate::{
+	node::{
+		Node,
+		CompareNode,
+		AppendNode
+	},
+	range::top,
+};
+
+/// The position of a `Node` in its linked list, expressed as the index among its siblings
+/// at every depth-level, starting from the root-level.
+///
+/// e.g `[1, 0]` is the first child of the second root-level node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NodePath {
+	pub indexes: Vec<usize>
+}
+
+impl NodePath {
+	/// Builds a `NodePath` from the given sibling indexes.
+	pub fn new(indexes: Vec<usize>) -> Self {
+		Self {
+			indexes
+		}
+	}
+
+	/// Number of depth-levels in the path.
+	pub fn depth(&self) -> usize {
+		self.indexes.len()
+	}
+}
+
+/// Formats the path as its indexes separated by `/`, e.g `1/0`.
+impl Display for NodePath {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let parts: Vec<String> = self.indexes.iter().map(|i| i.to_string()).collect();
+		write!(f, "{}", parts.join("/"))
+	}
+}
+
+/// Iterator over the descendants of a node in document order, yielding every `Node`
+/// along with its `NodePath`.
+///
+/// Usually built by calling `PathNode::paths`.
+pub struct Paths<T: Debug + Clone> {
+	root: Node<T>,
+	/// Length of the path of `root`, the indexes after it are relative to the subtree.
+	base: usize,
+	indexes: Vec<usize>,
+	next: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> Iterator for Paths<T> {
+	type Item = (NodePath, Node<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+		let path = NodePath::new(self.indexes.clone());
+
+		// step to the next node in document order, keeping the indexes in sync.
+		if let Some(child) = node.child() {
+			self.indexes.push(0);
+			self.next = Some(child);
+		} else {
+			let mut current = node.clone();
+
+			while self.indexes.len() > self.base {
+				if let Some(next) = current.next() {
+					*self.indexes.last_mut().unwrap() += 1;
+					self.next = Some(next);
+					break;
+				}
+
+				self.indexes.pop();
+
+				match current.parent() {
+					Some(parent) if !parent.ptr_eq(&self.root) => current = parent,
+					_ => break
+				}
+			}
+		}
+
+		Some((path, node))
+	}
+}
+
+pub trait PathNode<T: Debug + Clone> {
+	fn path(&self) -> NodePath;
+	fn paths(&self) -> Paths<T>;
+}
+
+impl<T: Debug + Clone> PathNode<T> for Node<T> {
+	/// Computes the `NodePath` of `&self` by counting the previous siblings
+	/// at every depth-level, up to the top parent.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1), node!(2, node!(3), node!(4)));
+	///		let four = list.first().unwrap().next().unwrap().get_last_child().unwrap();
+	///		assert_eq!(four.path().to_string(), "1/1");
+	/// }
+	/// ```
+	fn path(&self) -> NodePath {
+		let mut indexes = Vec::new();
+		let mut current = Some(self.clone());
+
+		while let Some(node) = current {
+			let mut index = 0;
+			let mut prev = node.prev();
+
+			while let Some(p) = prev {
+				index += 1;
+				prev = p.prev();
+			}
+
+			indexes.push(index);
+			current = node.parent();
+		}
+
+		indexes.reverse();
+		NodePath::new(indexes)
+	}
+
+	/// Iterates over the descendants of `&self` in document order, yielding each one along
+	/// with its `NodePath`. Paths are computed incrementally while walking,
+	/// instead of climbing up to the root-level for every node.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(0), node!(1, node!(2, node!(3)), node!(4)));
+	///		let one = list.first().unwrap().next().unwrap();
+	///
+	///		let paths: Vec<String> = one.paths().map(|(path, _)| path.to_string()).collect();
+	///		assert_eq!(paths, vec!["1/0", "1/0/0", "1/1"]);
+	///
+	///		for (path, node) in one.paths() {
+	///			assert_eq!(path, node.path());
+	///		}
+	/// }
+	/// ```
+	fn paths(&self) -> Paths<T> {
+		let indexes = self.path().indexes;
+		let base = indexes.len();
+		let mut paths = Paths {
+			root: self.clone(),
+			base,
+			indexes,
+			next: None
+		};
+
+		if let Some(child) = self.child() {
+			paths.indexes.push(0);
+			paths.next = Some(child);
+		}
+
+		paths
+	}
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Compares the positions of `&self` and `other` in document order: an ancestor comes before its descendants,
+	/// and a node before its next siblings along with their subtrees.
+	/// Returns `None` if the nodes are not part of the same linked list.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use std::cmp::Ordering;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2, node!(3))), node!(4));
+	///		let one = list.first().unwrap();
+	///		let three = one.child().unwrap().child().unwrap();
+	///		let four = one.next().unwrap();
+	///
+	///		assert_eq!(one.cmp_document_order(&three), Some(Ordering::Less));
+	///		assert_eq!(four.cmp_document_order(&three), Some(Ordering::Greater));
+	///		assert_eq!(three.cmp_document_order(&three), Some(Ordering::Equal));
+	///		assert_eq!(three.cmp_document_order(&node!(3)), None);
+	/// }
+	/// ```
+	pub fn cmp_document_order(&self, other: &Node<T>) -> Option<Ordering> {
+		if self.ptr_eq(other) {
+			return Some(Ordering::Equal);
+		}
+
+		if !top(self).ptr_eq(&top(other)) {
+			return None;
+		}
+
+		Some(self.path().cmp(&other.path()))
+	}
+	/// Walks down from `&self` following `components`, like `mkdir -p`: for every component the first child
+	/// matching it is entered, or a new last child with content `create(component)` is appended if none does.
+	/// Returns the node reached by the last component, `&self` if there is none.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// struct Key(&'static str);
+	///
+	/// impl CompareNode<String> for Key {
+	///		fn compare(&self, node: &Node<String>) -> bool {
+	///			node.get().content == self.0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let config = node!(String::from("config"));
+	///
+	///		let port = config.ensure_path(&[Key("server"), Key("http"), Key("port")], |k| k.0.to_string());
+	///		let host = config.ensure_path(&[Key("server"), Key("http"), Key("host")], |k| k.0.to_string());
+	///
+	///		// the intermediate nodes are shared.
+	///		assert!(port.parent().unwrap().ptr_eq(&host.parent().unwrap()));
+	///		assert!(config.child().unwrap().next().is_none());
+	///		assert_eq!(host.path().to_string(), "0/0/0/1");
+	/// }
+	/// ```
+	pub fn ensure_path<I: CompareNode<T>, F: FnMut(&I) -> T>(&self, components: &[I], mut create: F) -> Node<T> {
+		let mut current = self.clone();
+
+		for component in components {
+			let mut child = current.child();
+
+			while let Some(c) = child.as_ref() {
+				if component.compare(c) {
+					break;
+				}
+
+				child = c.next();
+			}
+
+			current = match child {
+				Some(child) => child,
+				None => {
+					let node = Node::new(create(component));
+					current.append_child(node.clone());
+					node
+				}
+			};
+		}
+
+		current
+	}
+}
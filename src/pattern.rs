@@ -0,0 +1,221 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+};
+
+use crate::node::{
+	Node,
+	CompareNode
+};
+
+type NodeMatcher<T> = Box<dyn Fn(&Node<T>) -> bool>;
+
+enum PatternKind<T: Debug + Clone> {
+	/// Matches a single node satisfying the closure.
+	Node(NodeMatcher<T>),
+	/// Matches any single node.
+	Wildcard,
+	/// Matches a node when the inner pattern matches the node itself or any of its descendants.
+	AnyDescendant(Box<Pattern<T>>)
+}
+
+/// A declarative description of a sub-structure, built from nested matchers.
+///
+/// - `Pattern::exact` matches a node by content equality.
+/// - `Pattern::ident` matches a node satisfying an identifier implementing `CompareNode`.
+/// - `Pattern::wildcard` matches any node.
+/// - `Pattern::any_descendant` matches a node if the inner pattern matches it or
+///   any node in its subtree.
+///
+/// Child patterns added with `Pattern::with_children` are matched in order against the
+/// direct children of the node: each one has to match a distinct child, children
+/// in between are skipped. On an `any_descendant` pattern, the child patterns and the
+/// capture apply to the node the pattern is matched against, not to the descendant.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::pattern::Pattern;
+///
+/// fn main() {
+///		let node = node!("table",
+///			node!("caption", node!("Prices")),
+///			node!("row", node!("cell"))
+///		);
+///
+///		let pattern = Pattern::exact("table").with_children(vec![
+///			Pattern::exact("caption").with_children(vec![
+///				Pattern::wildcard().capture("title")
+///			]),
+///			Pattern::any_descendant(Pattern::exact("cell").capture("cell"))
+///		]);
+///
+///		let captures = node.match_pattern(&pattern).unwrap();
+///		assert_eq!(captures.get("title").unwrap().clone().to_content(), "Prices");
+///		assert!(captures.get("cell").is_some());
+///
+///		let pattern = Pattern::any_descendant(Pattern::exact("cell"))
+///			.with_children(vec![Pattern::exact("row")])
+///			.capture("table");
+///
+///		let captures = node.match_pattern(&pattern).unwrap();
+///		assert!(captures.get("table").unwrap().ptr_eq(&node));
+///
+///		let pattern = Pattern::any_descendant(Pattern::exact("cell"))
+///			.with_children(vec![Pattern::exact("footer")]);
+///
+///		assert!(node.match_pattern(&pattern).is_none());
+/// }
+/// ```
+pub struct Pattern<T: Debug + Clone> {
+	kind: PatternKind<T>,
+	children: Vec<Pattern<T>>,
+	capture: Option<String>
+}
+
+impl<T: Debug + Clone + 'static> Pattern<T> {
+
+	fn from_kind(kind: PatternKind<T>) -> Self {
+		Self {
+			kind,
+			children: Vec::new(),
+			capture: None
+		}
+	}
+
+	/// Matches a node whose content is equal to `content`.
+	pub fn exact(content: T) -> Self where T: PartialEq {
+		Self::from_kind(PatternKind::Node(Box::new(move |node| node.get().content == content)))
+	}
+
+	/// Matches a node satisfying the identifier.
+	pub fn ident<I: CompareNode<T> + 'static>(ident: I) -> Self {
+		Self::from_kind(PatternKind::Node(Box::new(move |node| ident.compare(node))))
+	}
+
+	/// Matches any node.
+	pub fn wildcard() -> Self {
+		Self::from_kind(PatternKind::Wildcard)
+	}
+
+	/// Matches a node when `pattern` matches the node itself or any of its descendants.
+	pub fn any_descendant(pattern: Pattern<T>) -> Self {
+		Self::from_kind(PatternKind::AnyDescendant(Box::new(pattern)))
+	}
+}
+
+impl<T: Debug + Clone> Pattern<T> {
+
+	/// Sets the patterns the direct children of the matched node have to satisfy, in order.
+	pub fn with_children(mut self, children: Vec<Pattern<T>>) -> Self {
+		self.children = children;
+		self
+	}
+
+	/// Stores the matched node in the `Captures` under `name`.
+	pub fn capture(mut self, name: &str) -> Self {
+		self.capture = Some(name.to_string());
+		self
+	}
+
+	fn matches(&self, node: &Node<T>, captures: &mut Captures<T>) -> bool {
+		let own = match &self.kind {
+			PatternKind::Node(f) => f(node),
+			PatternKind::Wildcard => true,
+			PatternKind::AnyDescendant(inner) => Self::matches_descendant(inner, node, captures)
+		};
+
+		if !own || !self.matches_children(node, captures) {
+			return false;
+		}
+
+		if let Some(ref name) = self.capture {
+			captures.nodes.insert(name.clone(), node.clone());
+		}
+
+		true
+	}
+
+	fn matches_descendant(inner: &Pattern<T>, node: &Node<T>, captures: &mut Captures<T>) -> bool {
+		let mut attempt = captures.clone();
+
+		if inner.matches(node, &mut attempt) {
+			*captures = attempt;
+			return true;
+		}
+
+		let mut child = node.child();
+
+		while let Some(c) = child {
+			if Self::matches_descendant(inner, &c, captures) {
+				return true;
+			}
+			child = c.next();
+		}
+
+		false
+	}
+
+	fn matches_children(&self, node: &Node<T>, captures: &mut Captures<T>) -> bool {
+		let mut child = node.child();
+
+		for pattern in self.children.iter() {
+			loop {
+				let c = match child {
+					Some(c) => c,
+					None => return false
+				};
+
+				child = c.next();
+
+				let mut attempt = captures.clone();
+
+				if pattern.matches(&c, &mut attempt) {
+					*captures = attempt;
+					break;
+				}
+			}
+		}
+
+		true
+	}
+}
+
+/// The nodes captured by a successful `MatchNode::match_pattern`, by name.
+#[derive(Debug, Clone)]
+pub struct Captures<T: Debug + Clone> {
+	pub nodes: HashMap<String, Node<T>>
+}
+
+impl<T: Debug + Clone> Captures<T> {
+
+	/// Get the node captured under `name`, if any.
+	pub fn get(&self, name: &str) -> Option<&Node<T>> {
+		self.nodes.get(name)
+	}
+
+	/// Consume `self` and retrive the captured `Node`s.
+	pub fn into_nodes(self) -> HashMap<String, Node<T>> {
+		self.nodes
+	}
+}
+
+pub trait MatchNode<T: Debug + Clone> {
+	fn match_pattern(&self, pattern: &Pattern<T>) -> Option<Captures<T>>;
+}
+
+impl<T: Debug + Clone> MatchNode<T> for Node<T> {
+	/// Matches `&self` against the pattern, returning the captured nodes on success.
+	/// Refer to `Pattern` for the matching rules.
+	fn match_pattern(&self, pattern: &Pattern<T>) -> Option<Captures<T>> {
+		let mut captures = Captures {
+			nodes: HashMap::new()
+		};
+
+		if pattern.matches(self, &mut captures) {
+			return Some(captures);
+		} None
+	}
+}
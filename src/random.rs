@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+
+use rand::{
+	Rng,
+	seq::SliceRandom,
+};
+
+use crate::{
+	node::{
+		Node,
+		AppendNode,
+		DetachNode
+	},
+	iter::next_in_subtree,
+};
+
+impl<T: Debug + Clone> Node<T> {
+	/// Picks one of the descendants of `&self` ( `&self` excluded ) uniformly at random,
+	/// in a single pass over the subtree. Returns `None` if `&self` has no children.
+	///
+	/// Requires the `rand` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use rand::{
+	///		SeedableRng,
+	///		rngs::StdRng
+	/// };
+	///
+	/// fn main() {
+	///		let mut rng = StdRng::seed_from_u64(7);
+	///		let node = node!(1, node!(2, node!(3)), node!(4));
+	///
+	///		let picked = node.sample_descendant(&mut rng).unwrap().to_content();
+	///		assert!([2, 3, 4].contains(&picked));
+	///		assert!(node!(1).sample_descendant(&mut rng).is_none());
+	/// }
+	/// ```
+	pub fn sample_descendant<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Node<T>> {
+		let mut picked = None;
+		let mut seen = 0;
+		let mut next = self.child();
+
+		// reservoir sampling: the n-th node replaces the pick with probability 1/n.
+		while let Some(node) = next {
+			next = next_in_subtree(&node, self);
+			seen += 1;
+
+			if rng.gen_range(0..seen) == 0 {
+				picked = Some(node);
+			}
+		}
+
+		picked
+	}
+
+	/// Shuffles the children of `&self` uniformly at random, keeping their subtrees.
+	///
+	/// Requires the `rand` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use rand::{
+	///		SeedableRng,
+	///		rngs::StdRng
+	/// };
+	///
+	/// fn main() {
+	///		let mut rng = StdRng::seed_from_u64(7);
+	///		let node = node!(0, node!(1, node!(10)), node!(2), node!(3));
+	///		node.shuffle_children(&mut rng);
+	///
+	///		let children = node.children_range(0..3);
+	///		let mut contents: Vec<i32> = children.iter().map(|n| n.get().content).collect();
+	///		contents.sort();
+	///		assert_eq!(contents, vec![1, 2, 3]);
+	///
+	///		let one = children.iter().find(|n| n.get().content == 1).unwrap();
+	///		assert_eq!(one.child().unwrap().to_content(), 10);
+	/// }
+	/// ```
+	pub fn shuffle_children<R: Rng + ?Sized>(&self, rng: &mut R) {
+		if self.child().is_none_or(|child| child.next().is_none()) {
+			return;
+		}
+
+		let mut children = self.replace_children(Vec::new()).into_nodes();
+		children.shuffle(rng);
+		self.append_children(children);
+	}
+}
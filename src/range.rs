@@ -0,0 +1,779 @@
+use std::{
+	fmt::Debug,
+	ops,
+};
+
+use crate::{
+	node::{
+		Node,
+		NodeCollection,
+		AppendNode
+	},
+	list::List,
+	builder::TreeBuilder,
+	path::PathNode,
+	errors::HedelError,
+	oplog::{
+		record,
+		Change
+	},
+};
+
+/// Unlinks the run of siblings going from `first` to `last` ( inclusive ) from its linked list,
+/// fixing the pointers around it. The nodes in the run stay linked among themselves
+/// and keep their subtrees, but don't point to any parent anymore.
+pub(crate) fn unlink_run<T: Debug + Clone>(first: &Node<T>, last: &Node<T>) {
+	for node in RunIter::new(first, last) {
+		node.check_mutable();
+	}
+
+	record(Change::Removal(first, last));
+	first.touch();
+
+	if let Some(own) = first.own_list() {
+		own.release_roots(first, last);
+	}
+
+	let prev = first.prev();
+	let next = last.next();
+
+	match prev {
+		Some(ref prev) => prev.get_mut().next = next.clone(),
+		None => {
+			if let Some(parent) = first.parent() {
+				parent.get_mut().child = next.clone();
+			}
+		}
+	}
+
+	if let Some(ref next) = next {
+		next.get_mut().prev = prev.as_ref().map(|p| p.downgrade());
+	}
+
+	first.get_mut().prev = None;
+	last.get_mut().next = None;
+
+	let mut current = first.clone();
+
+	/* do */ {
+		current.get_mut().parent = None;
+	} while let Some(n) = current.next() {
+		current = n;
+		current.get_mut().parent = None;
+	}
+}
+
+/// Iterator over a run of siblings, from the first to the last one ( inclusive ).
+pub struct RunIter<T: Debug + Clone> {
+	next: Option<Node<T>>,
+	last: Option<Node<T>>
+}
+
+impl<T: Debug + Clone> RunIter<T> {
+	/// Iterates over the siblings from `first` to `last` ( inclusive ).
+	pub(crate) fn new(first: &Node<T>, last: &Node<T>) -> Self {
+		Self {
+			next: Some(first.clone()),
+			last: Some(last.clone())
+		}
+	}
+}
+
+impl<T: Debug + Clone> Iterator for RunIter<T> {
+	type Item = Node<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = self.next.take()?;
+
+		match self.last {
+			Some(ref last) if last.ptr_eq(&node) => self.last = None,
+			_ => self.next = node.next()
+		}
+
+		Some(node)
+	}
+}
+
+/// A view over a contiguous run of children of a `Node`, supporting bulk operations.
+/// The view holds the handles of the boundaries of the run: it's meant to be used
+/// right away, as structural changes to the children aren't reflected in it.
+///
+/// Usually built by calling `Node::children_range`.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let node = node!("body", node!("h1"), node!("p"), node!("p"), node!("footer"));
+///
+///		let range = node.children_range(1..3);
+///		assert_eq!(range.len(), 2);
+///
+///		let section = range.wrap(node!("section"));
+///		assert_eq!(node.child().unwrap().next().unwrap().to_content(), "section");
+///		assert_eq!(section.get_last_child().unwrap().to_content(), "p");
+///		assert_eq!(section.next().unwrap().to_content(), "footer");
+///
+///		let detached = node.children_range(0..2).detach_all();
+///		assert_eq!(detached.first().unwrap().next().unwrap().to_content(), "section");
+///		assert_eq!(node.child().unwrap().to_content(), "footer");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChildrenRange<T: Debug + Clone> {
+	parent: Node<T>,
+	start: usize,
+	len: usize,
+	bounds: Option<(Node<T>, Node<T>)>
+}
+
+impl<T: Debug + Clone> ChildrenRange<T> {
+	/// Number of children in the range.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns `true` if the range doesn't hold any child.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Get the first child in the range, if any.
+	pub fn first(&self) -> Option<Node<T>> {
+		self.bounds.as_ref().map(|(first, _)| first.clone())
+	}
+
+	/// Get the last child in the range, if any.
+	pub fn last(&self) -> Option<Node<T>> {
+		self.bounds.as_ref().map(|(_, last)| last.clone())
+	}
+
+	/// Iterates over the children in the range, in order.
+	pub fn iter(&self) -> RunIter<T> {
+		RunIter {
+			next: self.first(),
+			last: self.last()
+		}
+	}
+
+	/// Collects the children in the range, leaving them in place.
+	pub fn collect(&self) -> NodeCollection<T> {
+		NodeCollection::from_vec(self.iter().collect())
+	}
+
+	/// Detaches the children in the range and returns them as a new `List`,
+	/// keeping their order and their subtrees.
+	pub fn detach_all(self) -> List<T> {
+		match self.bounds {
+			Some((first, last)) => {
+				unlink_run(&first, &last);
+				List::new(first)
+			},
+			None => List::empty()
+		}
+	}
+
+	/// Inserts `wrapper` where the range is and moves the children in the range
+	/// after the children of `wrapper`, returning it.
+	/// If the range is empty, `wrapper` is simply inserted at its start position.
+	pub fn wrap(self, wrapper: Node<T>) -> Node<T> {
+		match self.bounds {
+			Some((first, last)) => {
+				let run: Vec<Node<T>> = RunIter { next: Some(first.clone()), last: Some(last.clone()) }.collect();
+
+				first.append_prev(wrapper.clone());
+				unlink_run(&first, &last);
+				wrapper.append_children(run);
+			},
+			None => {
+				match nth_child(&self.parent, self.start) {
+					Some(child) => child.append_prev(wrapper.clone()),
+					None => self.parent.append_child(wrapper.clone())
+				}
+			}
+		}
+
+		wrapper
+	}
+}
+
+impl<T: Debug + Clone> IntoIterator for &ChildrenRange<T> {
+	type Item = Node<T>;
+	type IntoIter = RunIter<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+pub(crate) fn nth_child<T: Debug + Clone>(parent: &Node<T>, n: usize) -> Option<Node<T>> {
+	let mut child = parent.child();
+
+	for _ in 0..n {
+		child = child?.next();
+	}
+
+	child
+}
+
+/// Copies the annotations ( e.g tags ) of `source` into `copy`, if their features are enabled.
+#[cfg_attr(not(any(feature = "tags", feature = "metadata")), allow(unused_variables))]
+fn copy_annotations<T: Debug + Clone>(source: &Node<T>, copy: &Node<T>) {
+	#[cfg(feature = "tags")]
+	{
+		copy.get_mut().tags = source.get().tags.clone();
+	}
+	#[cfg(feature = "metadata")]
+	{
+		copy.get_mut().meta = source.get().meta.clone();
+	}
+}
+
+/// Opens a copy of `node` in `builder`, carrying over its content and annotations but no links.
+fn start_copy<T: Debug + Clone>(builder: &mut TreeBuilder<T>, node: &Node<T>) {
+	let copy = builder.start_node(node.get().content.clone());
+	copy_annotations(node, &copy);
+}
+
+/// Builds a stand-alone copy of `node` without its subtree.
+fn shallow_copy<T: Debug + Clone>(node: &Node<T>) -> Node<T> {
+	let copy = Node::new(node.get().content.clone());
+	copy_annotations(node, &copy);
+	copy
+}
+
+/// Builds a stand-alone deep copy of `node` and its subtree.
+fn deep_copy<T: Debug + Clone>(node: &Node<T>) -> Node<T> {
+	let mut builder = TreeBuilder::new();
+	copy_subtree(&mut builder, node);
+
+	// the builder got exactly one root-level node.
+	let copy = builder.finish().first.get_mut().take().unwrap();
+	copy.get_mut().list = None;
+	copy
+}
+
+/// Deep copies `root` and its subtree into `builder`, walking it iteratively.
+pub(crate) fn copy_subtree<T: Debug + Clone>(builder: &mut TreeBuilder<T>, root: &Node<T>) {
+	let mut current = root.clone();
+	start_copy(builder, &current);
+
+	loop {
+		if let Some(child) = current.child() {
+			start_copy(builder, &child);
+			current = child;
+			continue;
+		}
+
+		loop {
+			builder.end_node();
+
+			if current.ptr_eq(root) {
+				return;
+			}
+
+			if let Some(next) = current.next() {
+				start_copy(builder, &next);
+				current = next;
+				break;
+			}
+
+			// `current` is a descendant of `root`, so it has a parent.
+			current = current.parent().unwrap();
+		}
+	}
+}
+
+/// Panics unless `to` is `from` or one of its following siblings.
+fn assert_run<T: Debug + Clone>(from: &Node<T>, to: &Node<T>) {
+	let mut current = from.clone();
+
+	while !current.ptr_eq(to) {
+		current = current.next().expect("`to` is not a following sibling of `from`");
+	}
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Detaches every sibling going from `from` to `to` ( inclusive ) and returns them
+	/// as a new `List`, keeping their order and their subtrees. The nodes around the run
+	/// get linked to each other, which makes this the primitive behind cut operations.
+	///
+	/// Panics unless `to` is `from` or one of its following siblings.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!('a'), node!('b', node!('x')), node!('c'), node!('d'));
+	///		let b = list.first().unwrap().next().unwrap();
+	///		let c = b.next().unwrap();
+	///
+	///		let cut = Node::detach_range(&b, &c);
+	///
+	///		assert_eq!(list.first().unwrap().next().unwrap().to_content(), 'd');
+	///		assert_eq!(cut.first().unwrap().child().unwrap().to_content(), 'x');
+	///		assert_eq!(cut.first().unwrap().get_last_sibling().unwrap().to_content(), 'c');
+	/// }
+	/// ```
+	pub fn detach_range(from: &Node<T>, to: &Node<T>) -> List<T> {
+		assert_run(from, to);
+		unlink_run(from, to);
+		List::new(from.clone())
+	}
+
+	/// Deep copies every sibling going from `from` to `to` ( inclusive ), along with their subtrees,
+	/// into a new `List`. The copies carry the contents and the annotations ( e.g tags ) of the originals,
+	/// while the source linked list is left untouched.
+	///
+	/// Panics unless `to` is `from` or one of its following siblings.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!('r', node!('a'), node!('b', node!('x')), node!('c'));
+	///		let a = node.child().unwrap();
+	///		let b = a.next().unwrap();
+	///
+	///		let copy = Node::clone_range(&a, &b);
+	///		let b_copy = copy.first().unwrap().next().unwrap();
+	///
+	///		assert!(!b_copy.ptr_eq(&b));
+	///		assert!(b_copy.next().is_none());
+	///		assert_eq!(b_copy.child().unwrap().parent().unwrap().to_content(), 'b');
+	///		assert_eq!(b.next().unwrap().to_content(), 'c');
+	/// }
+	/// ```
+	pub fn clone_range(from: &Node<T>, to: &Node<T>) -> List<T> {
+		assert_run(from, to);
+
+		let mut builder = TreeBuilder::new();
+
+		for node in (RunIter { next: Some(from.clone()), last: Some(to.clone()) }) {
+			copy_subtree(&mut builder, &node);
+		}
+
+		builder.finish()
+	}
+
+	/// Removes every sibling going from `from` to `to` ( inclusive ) and moves the root-level nodes
+	/// of `replacement` in their position, returning the removed nodes as a new `List`.
+	/// This is the linked list analogue of `Vec::splice`.
+	///
+	/// Panics unless `to` is `from` or one of its following siblings.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1), node!(2), node!(3), node!(4));
+	///		let two = node.child().unwrap().next().unwrap();
+	///		let three = two.next().unwrap();
+	///
+	///		let removed = Node::splice_siblings(&two, &three, list!(node!(20), node!(30), node!(35)));
+	///
+	///		let children: Vec<i32> = node.children_range(0..usize::MAX).iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(children, vec![1, 20, 30, 35, 4]);
+	///		assert_eq!(removed.first().unwrap().get_last_sibling().unwrap().to_content(), 3);
+	/// }
+	/// ```
+	pub fn splice_siblings(from: &Node<T>, to: &Node<T>, replacement: List<T>) -> List<T> {
+		assert_run(from, to);
+
+		from.splice_before(replacement);
+		unlink_run(from, to);
+		List::new(from.clone())
+	}
+
+	/// Moves every sibling going from `from` to `to` ( inclusive ) under `new_parent`, at the given position
+	/// among its children ( counted once the run is removed ), keeping their order and their subtrees.
+	/// If the position is bigger than the number of children, the run gets placed at the end.
+	///
+	/// Panics unless `to` is `from` or one of its following siblings,
+	/// or if `new_parent` is part of the run or a descendant of it.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!("inbox", node!("a"), node!("b"), node!("c")), node!("archive", node!("z")));
+	///		let inbox = list.first().unwrap();
+	///		let archive = inbox.next().unwrap();
+	///		let a = inbox.child().unwrap();
+	///
+	///		Node::move_range_to(&a, &a.next().unwrap(), &archive, 0);
+	///
+	///		assert_eq!(inbox.child().unwrap().to_content(), "c");
+	///		assert_eq!(archive.child().unwrap().to_content(), "a");
+	///		assert_eq!(archive.get_last_child().unwrap().prev().unwrap().to_content(), "b");
+	///		assert!(a.parent().unwrap().ptr_eq(&archive));
+	/// }
+	/// ```
+	pub fn move_range_to(from: &Node<T>, to: &Node<T>, new_parent: &Node<T>, position: usize) {
+		assert_run(from, to);
+
+		let run: Vec<Node<T>> = RunIter { next: Some(from.clone()), last: Some(to.clone()) }.collect();
+		let mut ancestor = Some(new_parent.clone());
+
+		while let Some(a) = ancestor {
+			assert!(!run.iter().any(|n| n.ptr_eq(&a)), "`new_parent` is part of the moved run");
+			ancestor = a.parent();
+		}
+
+		unlink_run(from, to);
+
+		match nth_child(new_parent, position) {
+			Some(child) => child.splice_before(List::new(from.clone())),
+			None => new_parent.append_children(run)
+		}
+	}
+
+	/// Get a view over the children of `&self` with index in `range`, refer to `ChildrenRange`.
+	/// Indexes past the last child are clamped to the number of children.
+	pub fn children_range(&self, range: ops::Range<usize>) -> ChildrenRange<T> {
+		let mut bounds = None;
+		let mut len = 0;
+
+		if range.start < range.end {
+			if let Some(first) = nth_child(self, range.start) {
+				let mut last = first.clone();
+				len = 1;
+
+				while len < range.end - range.start {
+					match last.next() {
+						Some(next) => {
+							last = next;
+							len += 1;
+						},
+						None => break
+					}
+				}
+
+				bounds = Some((first, last));
+			}
+		}
+
+		ChildrenRange {
+			parent: self.clone(),
+			start: range.start,
+			len,
+			bounds
+		}
+	}
+
+	/// Copies the content and the annotations ( e.g tags ) of `&self` into a fresh stand-alone node,
+	/// without children nor siblings. Unlike `Clone`, which gives another handle to the same node,
+	/// and `Node::deep_clone`, which copies the whole subtree.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!("li", node!("a")), node!("li"));
+	///		let li = list.first().unwrap();
+	///
+	///		let copy = li.clone_node_only();
+	///		assert!(!copy.ptr_eq(&li));
+	///		assert_eq!(copy.clone().to_content(), "li");
+	///		assert!(copy.child().is_none() && copy.next().is_none() && copy.list().is_none());
+	///
+	///		let tree = li.deep_clone();
+	///		assert_eq!(tree.child().unwrap().to_content(), "a");
+	///		assert!(tree.next().is_none());
+	/// }
+	/// ```
+	pub fn clone_node_only(&self) -> Node<T> {
+		shallow_copy(self)
+	}
+
+	/// Copies `&self` and its whole subtree into a fresh stand-alone node, contents and annotations included.
+	pub fn deep_clone(&self) -> Node<T> {
+		deep_copy(self)
+	}
+}
+
+pub(crate) fn child_count<T: Debug + Clone>(node: &Node<T>) -> usize {
+	let mut count = 0;
+	let mut child = node.child();
+
+	while let Some(c) = child {
+		count += 1;
+		child = c.next();
+	}
+
+	count
+}
+
+fn index_of<T: Debug + Clone>(node: &Node<T>) -> usize {
+	let mut index = 0;
+	let mut prev = node.prev();
+
+	while let Some(p) = prev {
+		index += 1;
+		prev = p.prev();
+	}
+
+	index
+}
+
+/// Get the ancestor of `node` ( or `node` itself ) which is a child of `ancestor`.
+fn child_towards<T: Debug + Clone>(ancestor: &Node<T>, node: &Node<T>) -> Node<T> {
+	let mut current = node.clone();
+
+	// `ancestor` is a proper ancestor of `node`, so the loop finds it.
+	while let Some(parent) = current.parent() {
+		if parent.ptr_eq(ancestor) {
+			break;
+		}
+
+		current = parent;
+	}
+
+	current
+}
+
+/// Get the deepest node which is an inclusive ancestor of both `a` and `b`, if any.
+fn common_ancestor<T: Debug + Clone>(a: &Node<T>, b: &Node<T>) -> Option<Node<T>> {
+	let mut ancestors = Vec::new();
+	let mut current = Some(a.clone());
+
+	while let Some(node) = current {
+		current = node.parent();
+		ancestors.push(node);
+	}
+
+	let mut current = Some(b.clone());
+
+	while let Some(node) = current {
+		if ancestors.iter().any(|a| a.ptr_eq(&node)) {
+			return Some(node);
+		}
+
+		current = node.parent();
+	}
+
+	None
+}
+
+/// Get the first root-level node of the linked list `node` belongs to.
+pub(crate) fn top<T: Debug + Clone>(node: &Node<T>) -> Node<T> {
+	let mut top = node.clone();
+
+	while let Some(parent) = top.parent() {
+		top = parent;
+	}
+
+	while let Some(prev) = top.prev() {
+		top = prev;
+	}
+
+	top
+}
+
+/// Moves out ( or deep copies, if `copy` ) the contents between the two boundary points,
+/// splitting the partially selected nodes: their shallow copies go in the result, holding the selected part.
+fn take_contents<T: Debug + Clone>(start: &(Node<T>, usize), end: &(Node<T>, usize), copy: bool) -> Vec<Node<T>> {
+	let (sc, so) = start;
+	let (ec, eo) = end;
+
+	let (common, start_partial, end_partial) = if sc.ptr_eq(ec) {
+		(sc.clone(), None, None)
+	} else {
+		// `Range::new` checked that both points are in the same tree.
+		let common = common_ancestor(sc, ec).unwrap();
+		let start_partial = (!common.ptr_eq(sc)).then(|| child_towards(&common, sc));
+		let end_partial = (!common.ptr_eq(ec)).then(|| child_towards(&common, ec));
+		(common, start_partial, end_partial)
+	};
+
+	let from = match start_partial {
+		Some(ref p) => index_of(p) + 1,
+		None => *so
+	};
+	let to = match end_partial {
+		Some(ref p) => index_of(p),
+		None => *eo
+	};
+
+	let contained: Vec<Node<T>> = if from < to { common.children_range(from..to).iter().collect() } else { Vec::new() };
+	let mut contents = Vec::new();
+
+	if let Some(p) = start_partial {
+		let split = shallow_copy(&p);
+		split.append_children(take_contents(start, &(p.clone(), child_count(&p)), copy));
+		contents.push(split);
+	}
+
+	if copy {
+		contents.extend(contained.iter().map(deep_copy));
+	} else if let (Some(first), Some(last)) = (contained.first(), contained.last()) {
+		unlink_run(first, last);
+		contents.extend(contained);
+	}
+
+	if let Some(p) = end_partial {
+		let split = shallow_copy(&p);
+		split.append_children(take_contents(&(p, 0), end, copy));
+		contents.push(split);
+	}
+
+	contents
+}
+
+/// A selection between two boundary points in a linked list, mirroring the DOM `Range`:
+/// every boundary point is a container `Node` and an offset, the index among its children.
+/// e.g `(ul, 1)` is the position between the first and the second child of `ul`.
+///
+/// When the boundary points are in different containers, the nodes crossed by the boundaries
+/// are partially selected: operations split them, like the DOM does.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::range::Range;
+///
+/// fn main() {
+///		let doc = node!("doc",
+///			node!("p1", node!("a"), node!("b")),
+///			node!("p2", node!("c"), node!("d"))
+///		);
+///		let p1 = doc.child().unwrap();
+///		let p2 = p1.next().unwrap();
+///
+///		// from between "a" and "b" to between "c" and "d".
+///		let range = Range::new((p1.clone(), 1), (p2.clone(), 1)).unwrap();
+///
+///		let copy = range.clone_contents();
+///		assert_eq!(copy.first().unwrap().child().unwrap().to_content(), "b");
+///
+///		let extracted = range.extract();
+///		let split = extracted.first().unwrap();
+///		assert_eq!(split.get().content, "p1");
+///		assert_eq!(split.next().unwrap().child().unwrap().to_content(), "c");
+///
+///		assert!(p1.child().unwrap().next().is_none());
+///		assert_eq!(p2.child().unwrap().to_content(), "d");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Range<T: Debug + Clone> {
+	start: (Node<T>, usize),
+	end: (Node<T>, usize)
+}
+
+impl<T: Debug + Clone> Range<T> {
+	/// Builds a `Range` between two boundary points.
+	/// Returns `HedelError::InvalidRange` if an offset is bigger than the number of children
+	/// of its container, if the points are in different linked lists or if `end` comes before `start`.
+	pub fn new(start: (Node<T>, usize), end: (Node<T>, usize)) -> Result<Self, HedelError> {
+		if start.1 > child_count(&start.0) || end.1 > child_count(&end.0) {
+			return Err(HedelError::InvalidRange("offset out of bounds"));
+		}
+
+		if !top(&start.0).ptr_eq(&top(&end.0)) {
+			return Err(HedelError::InvalidRange("boundary points in different linked lists"));
+		}
+
+		let mut start_position = start.0.path().indexes;
+		start_position.push(start.1);
+		let mut end_position = end.0.path().indexes;
+		end_position.push(end.1);
+
+		if start_position > end_position {
+			return Err(HedelError::InvalidRange("`end` comes before `start`"));
+		}
+
+		Ok(Self { start, end })
+	}
+
+	/// Builds an empty `Range` at the given boundary point.
+	pub fn collapsed(point: (Node<T>, usize)) -> Result<Self, HedelError> {
+		Self::new(point.clone(), point)
+	}
+
+	/// Get the start boundary point.
+	pub fn start(&self) -> &(Node<T>, usize) {
+		&self.start
+	}
+
+	/// Get the end boundary point.
+	pub fn end(&self) -> &(Node<T>, usize) {
+		&self.end
+	}
+
+	/// Returns `true` if both boundary points are the same.
+	pub fn is_collapsed(&self) -> bool {
+		self.start.0.ptr_eq(&self.end.0) && self.start.1 == self.end.1
+	}
+
+	/// Moves the selected contents out of the linked list and returns them as a new `List`.
+	/// Partially selected nodes stay in place with their unselected children,
+	/// while their shallow copies holding the selected children go in the result.
+	pub fn extract(self) -> List<T> {
+		List::from_nodes(take_contents(&self.start, &self.end, false))
+	}
+
+	/// Deep copies the selected contents into a new `List`, leaving the linked list untouched.
+	/// Partially selected nodes get copied with the selected children only.
+	pub fn clone_contents(&self) -> List<T> {
+		List::from_nodes(take_contents(&self.start, &self.end, true))
+	}
+
+	/// Removes the selected contents from the linked list.
+	pub fn delete_contents(self) {
+		self.extract();
+	}
+
+	/// Moves the selected contents after the children of `node`, and inserts `node`
+	/// at the start boundary point, returning it.
+	/// Returns `HedelError::InvalidRange` if the range partially selects a node,
+	/// i.e the boundary points are in different containers.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::range::Range;
+	///
+	/// fn main() {
+	///		let p = node!("p", node!("a"), node!("b"), node!("c"));
+	///
+	///		let range = Range::new((p.clone(), 1), (p.clone(), 3)).unwrap();
+	///		let em = range.surround_with(node!("em")).unwrap();
+	///
+	///		assert_eq!(p.child().unwrap().next().unwrap().to_content(), "em");
+	///		assert_eq!(em.child().unwrap().to_content(), "b");
+	///		assert_eq!(em.get_last_child().unwrap().to_content(), "c");
+	/// }
+	/// ```
+	pub fn surround_with(self, node: Node<T>) -> Result<Node<T>, HedelError> {
+		let (container, offset) = self.start;
+
+		if !container.ptr_eq(&self.end.0) {
+			return Err(HedelError::InvalidRange("the range partially selects a node"));
+		}
+
+		Ok(container.children_range(offset..self.end.1).wrap(node))
+	}
+}
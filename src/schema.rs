@@ -0,0 +1,282 @@
+use std::{
+	collections::HashMap,
+	fmt::Debug,
+	hash::Hash,
+};
+
+use crate::{
+	node::Node,
+	list::List,
+	path::NodePath,
+};
+
+/// How many children of a given kind a parent is allowed to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cardinality {
+	pub min: usize,
+	/// `None` means there isn't an upper bound.
+	pub max: Option<usize>
+}
+
+impl Cardinality {
+	/// Any number of children, including none.
+	pub fn any() -> Self {
+		Self { min: 0, max: None }
+	}
+
+	/// At most one child.
+	pub fn optional() -> Self {
+		Self { min: 0, max: Some(1) }
+	}
+
+	/// Exactly one child.
+	pub fn one() -> Self {
+		Self { min: 1, max: Some(1) }
+	}
+
+	/// One or more children.
+	pub fn at_least_one() -> Self {
+		Self { min: 1, max: None }
+	}
+
+	fn contains(&self, count: usize) -> bool {
+		count >= self.min && self.max.is_none_or(|max| count <= max)
+	}
+}
+
+/// Describes the allowed parent/child relationships in a tree.
+///
+/// Every node is classified into a kind `K` by a user callback on its content.
+/// Kinds declared with `TreeSchema::leaf` or `TreeSchema::allow_child` only accept
+/// the listed child kinds, in the given cardinality. Kinds never declared are unconstrained.
+/// If any kind is declared with `TreeSchema::root`, only those kinds are accepted at the root-level.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+/// use hedel_rs::schema::{TreeSchema, Cardinality, ViolationKind};
+///
+/// fn main() {
+///		let schema = TreeSchema::new(|content: &&str| content.to_string())
+///			.root("ul".to_string())
+///			.allow_child("ul".to_string(), "li".to_string(), Cardinality::at_least_one())
+///			.leaf("li".to_string());
+///
+///		let valid = list!(node!("ul", node!("li"), node!("li")));
+///		assert!(valid.validate_schema(&schema).is_empty());
+///
+///		// a "li" doesn't accept any child.
+///		let invalid = list!(node!("ul", node!("li", node!("ul", node!("li")))));
+///		let violations = invalid.validate_schema(&schema);
+///		assert_eq!(violations.len(), 1);
+///		assert_eq!(violations[0].path.to_string(), "0/0/0");
+///
+///		let schema = TreeSchema::new(|content: &&str| content.to_string())
+///			.allow_child("table".to_string(), "caption".to_string(), Cardinality::one())
+///			.allow_child("table".to_string(), "row".to_string(), Cardinality::at_least_one());
+///
+///		let violations = list!(node!("table")).validate_schema(&schema);
+///		let missing: Vec<ViolationKind<String>> = violations.into_iter().map(|v| v.kind).collect();
+///		assert_eq!(missing, vec![
+///			ViolationKind::Cardinality { parent: "table".to_string(), child: "caption".to_string(), count: 0 },
+///			ViolationKind::Cardinality { parent: "table".to_string(), child: "row".to_string(), count: 0 }
+///		]);
+/// }
+/// ```
+pub struct TreeSchema<T, K: Eq + Hash> {
+	key: Box<dyn Fn(&T) -> K>,
+	roots: Vec<K>,
+	/// The allowed children of every declared kind, in declaration order.
+	rules: HashMap<K, Vec<(K, Cardinality)>>
+}
+
+impl<T, K: Eq + Hash + Clone> TreeSchema<T, K> {
+
+	/// Builds a schema without any rule, given the callback classifying the contents.
+	pub fn new<F: Fn(&T) -> K + 'static>(key: F) -> Self {
+		Self {
+			key: Box::new(key),
+			roots: Vec::new(),
+			rules: HashMap::new()
+		}
+	}
+
+	/// Allows nodes of kind `kind` at the root-level.
+	pub fn root(mut self, kind: K) -> Self {
+		self.roots.push(kind);
+		self
+	}
+
+	/// Declares `kind` as a kind which doesn't accept any child.
+	pub fn leaf(mut self, kind: K) -> Self {
+		self.rules.entry(kind).or_default();
+		self
+	}
+
+	/// Allows children of kind `child` under nodes of kind `parent`, in the given cardinality.
+	pub fn allow_child(mut self, parent: K, child: K, cardinality: Cardinality) -> Self {
+		let rules = self.rules.entry(parent).or_default();
+
+		match rules.iter_mut().find(|(kind, _)| *kind == child) {
+			Some(rule) => rule.1 = cardinality,
+			None => rules.push((child, cardinality))
+		}
+
+		self
+	}
+}
+
+/// What rule of a `TreeSchema` was broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind<K> {
+	/// A node of this kind is not allowed at the root-level.
+	RootNotAllowed(K),
+	/// A node of kind `child` is not allowed under a node of kind `parent`.
+	ChildNotAllowed { parent: K, child: K },
+	/// A node of kind `parent` holds `count` children of kind `child`,
+	/// outside of the allowed cardinality.
+	Cardinality { parent: K, child: K, count: usize }
+}
+
+/// A broken rule of a `TreeSchema`, along with the path of the offending node.
+/// For `ViolationKind::Cardinality` the path points to the parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation<K> {
+	pub path: NodePath,
+	pub kind: ViolationKind<K>
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Validates the whole linked list against the schema, returning every violation found.
+	/// An empty `Vec` means the list is valid.
+	///
+	/// The order is deterministic: the nodes are visited in document order, and the cardinality
+	/// violations of a node come after the ones of its subtree, in the order the rules were declared.
+	pub fn validate_schema<K: Eq + Hash + Clone>(&self, schema: &TreeSchema<T, K>) -> Vec<SchemaViolation<K>> {
+		let mut violations = Vec::new();
+		let mut path = Vec::new();
+		let mut current = self.first();
+		let mut index = 0;
+
+		while let Some(node) = current {
+			path.push(index);
+
+			let kind = (schema.key)(&node.get().content);
+
+			if !schema.roots.is_empty() && !schema.roots.contains(&kind) {
+				violations.push(SchemaViolation {
+					path: NodePath::new(path.clone()),
+					kind: ViolationKind::RootNotAllowed(kind.clone())
+				});
+			}
+
+			validate_node(schema, &node, kind, &mut path, &mut violations);
+
+			path.pop();
+			index += 1;
+			current = node.next();
+		}
+
+		violations
+	}
+}
+
+/// A node whose children are being validated, refer to `validate_node`.
+struct Frame<'a, T: Debug + Clone, K> {
+	kind: K,
+	rules: Option<&'a Vec<(K, Cardinality)>>,
+	/// The number of children matching every rule, in the same order.
+	counts: Vec<usize>,
+	next: Option<Node<T>>,
+	index: usize
+}
+
+impl<'a, T: Debug + Clone, K: Eq + Hash + Clone> Frame<'a, T, K> {
+	fn new(schema: &'a TreeSchema<T, K>, node: &Node<T>, kind: K) -> Self {
+		let rules = schema.rules.get(&kind);
+
+		Self {
+			counts: vec![0; rules.map_or(0, |rules| rules.len())],
+			kind,
+			rules,
+			next: node.child(),
+			index: 0
+		}
+	}
+
+	/// Pushes a violation for every rule whose cardinality isn't met, `path` being the path of the node.
+	fn check_cardinality(self, path: &[usize], violations: &mut Vec<SchemaViolation<K>>) {
+		let rules = match self.rules {
+			Some(rules) => rules,
+			None => return
+		};
+
+		for ((child_kind, cardinality), count) in rules.iter().zip(self.counts) {
+			if !cardinality.contains(count) {
+				violations.push(SchemaViolation {
+					path: NodePath::new(path.to_vec()),
+					kind: ViolationKind::Cardinality {
+						parent: self.kind.clone(),
+						child: child_kind.clone(),
+						count
+					}
+				});
+			}
+		}
+	}
+}
+
+/// Validates the subtree of `node`, whose path is `path`, with an explicit stack so deep trees
+/// can't overflow it. The cardinality of a node is checked once all its descendants are.
+fn validate_node<T: Debug + Clone, K: Eq + Hash + Clone>(
+	schema: &TreeSchema<T, K>,
+	node: &Node<T>,
+	kind: K,
+	path: &mut Vec<usize>,
+	violations: &mut Vec<SchemaViolation<K>>
+) {
+	let mut stack = vec![Frame::new(schema, node, kind)];
+
+	while let Some(frame) = stack.last_mut() {
+		let child = match frame.next.take() {
+			Some(child) => child,
+			None => {
+				if let Some(frame) = stack.pop() {
+					frame.check_cardinality(path, violations);
+				}
+
+				// the path of `node` belongs to the caller.
+				if !stack.is_empty() {
+					path.pop();
+				}
+
+				continue;
+			}
+		};
+
+		frame.next = child.next();
+		path.push(frame.index);
+		frame.index += 1;
+
+		let child_kind = (schema.key)(&child.get().content);
+
+		if let Some(rules) = frame.rules {
+			match rules.iter().position(|(kind, _)| *kind == child_kind) {
+				Some(rule) => frame.counts[rule] += 1,
+				None => {
+					violations.push(SchemaViolation {
+						path: NodePath::new(path.clone()),
+						kind: ViolationKind::ChildNotAllowed {
+							parent: frame.kind.clone(),
+							child: child_kind.clone()
+						}
+					});
+				}
+			}
+		}
+
+		stack.push(Frame::new(schema, &child, child_kind));
+	}
+}
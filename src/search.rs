@@ -0,0 +1,281 @@
+use std::{
+	cmp::{
+		Ordering,
+		Reverse
+	},
+	fmt::Debug,
+};
+
+use crate::{
+	node::{
+		Node,
+		NodeCollection,
+		CompareNode
+	},
+	iter::{
+		next_in_subtree,
+		skip_in_subtree
+	},
+};
+
+pub trait SearchNode<T: Debug + Clone> {
+	fn find_best<S: PartialOrd, F: FnMut(&Node<T>) -> S>(&self, score: F) -> Option<Node<T>>;
+	fn find_best_pruned<S, F, B>(&self, score: F, bound: B) -> Option<Node<T>>
+	where
+		S: PartialOrd,
+		F: FnMut(&Node<T>) -> S,
+		B: FnMut(&Node<T>) -> S;
+	fn collect_top_k<F: FnMut(&Node<T>, &Node<T>) -> Ordering>(&self, k: usize, cmp: F) -> NodeCollection<T>;
+	fn max_by_key<K: Ord, F: FnMut(&Node<T>) -> K>(&self, f: F) -> Option<Node<T>>;
+	fn min_by_key<K: Ord, F: FnMut(&Node<T>) -> K>(&self, f: F) -> Option<Node<T>>;
+	fn count_matching<I: CompareNode<T>>(&self, ident: &I) -> usize;
+	fn any_match<I: CompareNode<T>>(&self, ident: &I) -> bool;
+	fn all_match<I: CompareNode<T>>(&self, ident: &I) -> bool;
+}
+
+impl<T: Debug + Clone> SearchNode<T> for Node<T> {
+	/// Traverses the descendants of `&self` and returns the one with the highest score,
+	/// the first one in document order on ties.
+	/// Returns `None` if there aren't any descendants.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0i32, node!(3, node!(-8)), node!(5));
+	///		let best = node.find_best(|n| n.get().content.abs()).unwrap();
+	///		assert_eq!(best.to_content(), -8);
+	/// }
+	/// ```
+	fn find_best<S: PartialOrd, F: FnMut(&Node<T>) -> S>(&self, mut score: F) -> Option<Node<T>> {
+		let mut best: Option<(S, Node<T>)> = None;
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			let s = score(&node);
+			next = next_in_subtree(&node, self);
+
+			match best {
+				Some((ref best_score, _)) if s <= *best_score => {},
+				_ => best = Some((s, node))
+			}
+		}
+
+		best.map(|(_, node)| node)
+	}
+
+	/// Like `SearchNode::find_best`, but calls `bound` before visiting every node: it must return
+	/// an upper bound of the scores in the subtree of that node ( the node included ).
+	/// When the bound can't beat the best score found so far, the whole subtree is skipped,
+	/// which lets heuristic searches over hierarchical data avoid most of the traversal.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		// every node holds its own value and the maximum value of its subtree.
+	///		let node = node!((0i32, 9i32),
+	///			node!((9, 9), node!((1, 1))),
+	///			node!((2, 4), node!((4, 4)))
+	///		);
+	///
+	///		let mut visited = 0;
+	///		let best = node.find_best_pruned(
+	///			|n| { visited += 1; n.get().content.0 },
+	///			|n| n.get().content.1
+	///		).unwrap();
+	///
+	///		assert_eq!(best.to_content(), (9, 9));
+	///		// only the first child gets scored, every other subtree is pruned.
+	///		assert_eq!(visited, 1);
+	/// }
+	/// ```
+	fn find_best_pruned<S, F, B>(&self, mut score: F, mut bound: B) -> Option<Node<T>>
+	where
+		S: PartialOrd,
+		F: FnMut(&Node<T>) -> S,
+		B: FnMut(&Node<T>) -> S
+	{
+		let mut best: Option<(S, Node<T>)> = None;
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if let Some((ref best_score, _)) = best {
+				if bound(&node) <= *best_score {
+					next = skip_in_subtree(&node, self);
+					continue;
+				}
+			}
+
+			let s = score(&node);
+			next = next_in_subtree(&node, self);
+
+			match best {
+				Some((ref best_score, _)) if s <= *best_score => {},
+				_ => best = Some((s, node))
+			}
+		}
+
+		best.map(|(_, node)| node)
+	}
+
+	/// Collects the `k` greatest descendants of `&self` according to `cmp`, greatest first,
+	/// in document order on ties. Only the best `k` nodes are kept in memory during the traversal,
+	/// instead of collecting every descendant and sorting afterwards.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(4, node!(7), node!(1)), node!(9), node!(4));
+	///
+	///		let top: Vec<i32> = node.collect_top_k(3, |a, b| a.get().content.cmp(&b.get().content))
+	///			.into_iter()
+	///			.map(|n| n.to_content())
+	///			.collect();
+	///
+	///		assert_eq!(top, vec![9, 7, 4]);
+	/// }
+	/// ```
+	fn collect_top_k<F: FnMut(&Node<T>, &Node<T>) -> Ordering>(&self, k: usize, mut cmp: F) -> NodeCollection<T> {
+		if k == 0 {
+			return NodeCollection::new();
+		}
+
+		// kept sorted from the greatest to the smallest.
+		let mut best: Vec<Node<T>> = Vec::with_capacity(k);
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			next = next_in_subtree(&node, self);
+
+			// earlier nodes win ties, so the new one goes after every node not smaller than it.
+			let index = best.partition_point(|b| cmp(b, &node) != Ordering::Less);
+
+			if index < k {
+				if best.len() == k {
+					best.pop();
+				}
+
+				best.insert(index, node);
+			}
+		}
+
+		NodeCollection::from_vec(best)
+	}
+
+	/// Get the descendant of `&self` with the greatest key, the first one in document order on ties.
+	/// Returns `None` if there aren't any descendants.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("dir", node!("a.txt"), node!("sub", node!("longer.txt")), node!("b.rs"));
+	///
+	///		assert_eq!(node.max_by_key(|n| n.get().content.len()).unwrap().to_content(), "longer.txt");
+	///		assert_eq!(node.min_by_key(|n| n.get().content.len()).unwrap().to_content(), "sub");
+	/// }
+	/// ```
+	fn max_by_key<K: Ord, F: FnMut(&Node<T>) -> K>(&self, f: F) -> Option<Node<T>> {
+		self.find_best(f)
+	}
+
+	/// Get the descendant of `&self` with the smallest key, the first one in document order on ties.
+	/// Returns `None` if there aren't any descendants.
+	fn min_by_key<K: Ord, F: FnMut(&Node<T>) -> K>(&self, mut f: F) -> Option<Node<T>> {
+		self.find_best(|node| Reverse(f(node)))
+	}
+
+	/// Counts the descendants of `&self` matching `ident`, without collecting them.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// pub struct Even;
+	///
+	/// impl CompareNode<i32> for Even {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content % 2 == 0
+	///		}
+	/// }
+	///
+	/// pub struct Positive;
+	///
+	/// impl CompareNode<i32> for Positive {
+	///		fn compare(&self, node: &Node<i32>) -> bool {
+	///			node.get().content > 0
+	///		}
+	/// }
+	///
+	/// fn main() {
+	///		let node = node!(0, node!(1, node!(2)), node!(4), node!(7));
+	///
+	///		assert_eq!(node.count_matching(&Even), 2);
+	///		assert!(node.any_match(&Even));
+	///		assert!(!node.all_match(&Even));
+	///		assert!(node.all_match(&Positive));
+	/// }
+	/// ```
+	fn count_matching<I: CompareNode<T>>(&self, ident: &I) -> usize {
+		let mut count = 0;
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				count += 1;
+			}
+
+			next = next_in_subtree(&node, self);
+		}
+
+		count
+	}
+
+	/// Returns `true` if any descendant of `&self` matches `ident`,
+	/// stopping at the first match.
+	fn any_match<I: CompareNode<T>>(&self, ident: &I) -> bool {
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if ident.compare(&node) {
+				return true;
+			}
+
+			next = next_in_subtree(&node, self);
+		}
+
+		false
+	}
+
+	/// Returns `true` if every descendant of `&self` matches `ident`,
+	/// stopping at the first mismatch. Returns `true` if there aren't any descendants.
+	fn all_match<I: CompareNode<T>>(&self, ident: &I) -> bool {
+		let mut next = self.child();
+
+		while let Some(node) = next {
+			if !ident.compare(&node) {
+				return false;
+			}
+
+			next = next_in_subtree(&node, self);
+		}
+
+		true
+	}
+}
@@ -0,0 +1,100 @@
+use std::{
+	fmt::Debug,
+	pin::Pin,
+	task::{
+		Context,
+		Poll
+	},
+};
+
+use futures_core::Stream;
+
+use crate::{
+	node::Node,
+	iter::next_in_subtree,
+};
+
+/// Stream over the descendants of a node in document order, handing control back
+/// to the executor every `yield_every` nodes so huge trees don't block it.
+///
+/// Usually built by calling `Node::descendants_stream`. Requires the `async` feature.
+pub struct DescendantsStream<T: Debug + Clone> {
+	root: Node<T>,
+	next: Option<Node<T>>,
+	yield_every: usize,
+	/// Nodes yielded since the last time control was handed back.
+	streak: usize
+}
+
+impl<T: Debug + Clone> DescendantsStream<T> {
+	/// Sets how many nodes are yielded before handing control back to the executor.
+	///
+	/// Panics if `n` is 0.
+	pub fn yield_every(mut self, n: usize) -> Self {
+		assert!(n != 0, "the number of nodes between yields must be non-zero");
+		self.yield_every = n;
+		self
+	}
+}
+
+impl<T: Debug + Clone> Stream for DescendantsStream<T> {
+	type Item = Node<T>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+
+		let node = match this.next.take() {
+			Some(node) => node,
+			None => return Poll::Ready(None)
+		};
+
+		if this.streak == this.yield_every {
+			// wake right away: the task gets polled again once the executor ran the others.
+			this.streak = 0;
+			this.next = Some(node);
+			cx.waker().wake_by_ref();
+			return Poll::Pending;
+		}
+
+		this.streak += 1;
+		this.next = next_in_subtree(&node, &this.root);
+		Poll::Ready(Some(node))
+	}
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Streams the descendants of `&self` ( `&self` excluded ) in document order,
+	/// handing control back to the executor every 64 nodes, refer to `DescendantsStream::yield_every`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use futures::{
+	///		executor::block_on,
+	///		StreamExt
+	/// };
+	///
+	/// fn main() {
+	///		let node = node!(1, node!(2, node!(3)), node!(4));
+	///
+	///		let contents: Vec<i32> = block_on(
+	///			node.descendants_stream()
+	///				.yield_every(2)
+	///				.map(|n| n.to_content())
+	///				.collect()
+	///		);
+	///
+	///		assert_eq!(contents, vec![2, 3, 4]);
+	/// }
+	/// ```
+	pub fn descendants_stream(&self) -> DescendantsStream<T> {
+		DescendantsStream {
+			root: self.clone(),
+			next: self.child(),
+			yield_every: 64,
+			streak: 0
+		}
+	}
+}
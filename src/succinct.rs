@@ -0,0 +1,425 @@
+use std::fmt::Debug;
+
+use crate::{
+	node::Node,
+	list::List,
+	builder::TreeBuilder,
+	iter::next_in_subtree,
+	errors::HedelError,
+};
+
+/// The number of words covered by every entry of the rank directory of `Bits`.
+const BLOCK: usize = 8;
+
+/// A bitvector with a rank directory: the number of ones before every block of `BLOCK` words.
+#[derive(Debug, Clone, PartialEq)]
+struct Bits {
+	words: Vec<u64>,
+	len: usize,
+	blocks: Vec<usize>
+}
+
+impl Bits {
+	fn new() -> Self {
+		Self {
+			words: Vec::new(),
+			len: 0,
+			blocks: Vec::new()
+		}
+	}
+
+	fn push(&mut self, bit: bool) {
+		if self.len == self.words.len() * 64 {
+			self.words.push(0);
+		}
+
+		if bit {
+			if let Some(word) = self.words.last_mut() {
+				*word |= 1 << (self.len % 64);
+			}
+		}
+
+		self.len += 1;
+	}
+
+	/// Builds the rank directory, once every bit is pushed.
+	fn index(mut self) -> Self {
+		let mut ones = 0;
+
+		self.blocks = self.words.chunks(BLOCK).map(|block| {
+			let before = ones;
+			ones += block.iter().map(|word| word.count_ones() as usize).sum::<usize>();
+			before
+		}).collect();
+
+		self
+	}
+
+	fn get(&self, position: usize) -> bool {
+		(self.words[position / 64] >> (position % 64)) & 1 == 1
+	}
+
+	/// Returns the number of ones before `position`, which has to be in bounds.
+	fn rank(&self, position: usize) -> usize {
+		let word = position / 64;
+		let block = word / BLOCK;
+		let mut ones = self.blocks[block];
+
+		for w in self.words[block * BLOCK..word].iter() {
+			ones += w.count_ones() as usize;
+		}
+
+		ones + (self.words[word] & ((1 << (position % 64)) - 1)).count_ones() as usize
+	}
+
+	/// Returns the position of the one preceded by `nth` other ones, if any.
+	fn select(&self, nth: usize) -> Option<usize> {
+		let block = self.blocks.partition_point(|ones| *ones <= nth).checked_sub(1)?;
+		let mut ones = self.blocks[block];
+
+		for (w, word) in self.words.iter().enumerate().skip(block * BLOCK) {
+			let count = word.count_ones() as usize;
+
+			if ones + count > nth {
+				let mut word = *word;
+
+				// clears the lowest ones until the wanted one is the lowest.
+				for _ in 0..nth - ones {
+					word &= word - 1;
+				}
+
+				return Some(w * 64 + word.trailing_zeros() as usize);
+			}
+
+			ones += count;
+		}
+
+		None
+	}
+
+	/// Returns the position of the parenthesis closing the one open at `position`.
+	fn close(&self, position: usize) -> usize {
+		let mut excess = 0;
+
+		for p in position..self.len {
+			match self.get(p) {
+				true => excess += 1,
+				false => excess -= 1
+			}
+
+			if excess == 0 {
+				return p;
+			}
+		}
+
+		self.len
+	}
+
+	/// Returns the position of the parenthesis enclosing the one open at `position`, if any.
+	fn enclose(&self, position: usize) -> Option<usize> {
+		let mut excess = 0;
+
+		for p in (0..position).rev() {
+			match self.get(p) {
+				true if excess == 0 => return Some(p),
+				true => excess -= 1,
+				false => excess += 1
+			}
+		}
+
+		None
+	}
+}
+
+/// A compact, immutable copy of a subtree for read-only consumers.
+///
+/// The contents are stored in a `Vec` in document order, so a node is addressed by its index,
+/// and the topology is a sequence of balanced parentheses: every node opens a parenthesis ( `true` ),
+/// its subtree follows, and then it closes it ( `false` ). The parentheses are packed in a bitvector,
+/// two bits per node plus a small rank directory, and there are no pointers or cells to follow:
+/// a node gets to its parenthesis and back with select and rank, so `first_child` and `depth` are O(1),
+/// while `next_sibling`, `subtree_size` and `parent` scan the parentheses for the matching one.
+///
+/// Usually built by calling `Node::to_succinct` or `List::to_succinct`, and turned back into
+/// a linked list with `List::from_succinct`. Several root-level nodes are just balanced sequences one after the other.
+///
+/// # Example
+///
+/// ```
+/// use hedel_rs::prelude::*;
+/// use hedel_rs::*;
+///
+/// fn main() {
+///		let node = node!("html", node!("head"), node!("body", node!("p"), node!("p")));
+///		let tree = node.to_succinct();
+///
+///		assert_eq!(tree.contents(), &["html", "head", "body", "p", "p"]);
+///		assert_eq!(
+///			tree.parens().collect::<Vec<_>>(),
+///			vec![true, true, false, true, true, false, true, false, false, false]
+///		);
+///
+///		let body = tree.next_sibling(tree.first_child(0).unwrap()).unwrap();
+///		assert_eq!(tree.content(body), Some(&"body"));
+///		assert_eq!(tree.children(body).collect::<Vec<_>>(), vec![3, 4]);
+///		assert_eq!(tree.subtree_size(body), 3);
+///		assert_eq!(tree.parent(4), Some(body));
+///		assert_eq!(tree.depth(4), 2);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Succinct<T> {
+	contents: Vec<T>,
+	parens: Bits
+}
+
+impl<T> Succinct<T> {
+	/// Builds a `Succinct` from its raw parts, e.g data embedded in the binary or memory-mapped,
+	/// refer to `Succinct::contents` and `Succinct::words`.
+	///
+	/// Returns `HedelError::Encoding` if the parentheses are unbalanced, or if there aren't
+	/// exactly two of them for every content.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::succinct::Succinct;
+	///
+	/// fn main() {
+	///		// (1 (2) (3)) (4), starting from the lowest bit.
+	///		let words = vec![0b0100_1011];
+	///		let tree = Succinct::from_parts(vec![1, 2, 3, 4], words).unwrap();
+	///
+	///		let list = List::from_succinct(&tree);
+	///		assert_eq!(list.first().unwrap().get_last_child().unwrap().to_content(), 3);
+	///		assert_eq!(list.last().unwrap().to_content(), 4);
+	///		assert!(list.audit().is_empty());
+	///
+	///		assert_eq!(list.to_succinct(), tree);
+	///		assert!(Succinct::from_parts(vec![1], vec![0b11]).is_err());
+	/// }
+	/// ```
+	pub fn from_parts(contents: Vec<T>, words: Vec<u64>) -> Result<Self, HedelError> {
+		let len = contents.len() * 2;
+
+		if words.len() != len.div_ceil(64) {
+			return Err(HedelError::Encoding(format!("{} words for {} contents", words.len(), contents.len())));
+		}
+
+		let parens = Bits {
+			words,
+			len,
+			blocks: Vec::new()
+		};
+
+		let tail = len % 64;
+
+		if tail != 0 && parens.words.last().is_some_and(|word| word >> tail != 0) {
+			return Err(HedelError::Encoding("a parenthesis is set past the last content".to_string()));
+		}
+
+		let mut depth: usize = 0;
+
+		for p in 0..len {
+			match parens.get(p) {
+				true => depth += 1,
+				false => {
+					depth = depth.checked_sub(1)
+						.ok_or_else(|| HedelError::Encoding("a parenthesis closes without being opened".to_string()))?;
+				}
+			}
+		}
+
+		if depth != 0 {
+			return Err(HedelError::Encoding(format!("{} parentheses are never closed", depth)));
+		}
+
+		Ok(Self {
+			contents,
+			parens: parens.index()
+		})
+	}
+
+	/// Consumes the `Succinct`, returning the contents and the packed parentheses.
+	pub fn into_parts(self) -> (Vec<T>, Vec<u64>) {
+		(self.contents, self.parens.words)
+	}
+
+	/// Get the number of nodes.
+	pub fn len(&self) -> usize {
+		self.contents.len()
+	}
+
+	/// Returns `true` if there are no nodes.
+	pub fn is_empty(&self) -> bool {
+		self.contents.is_empty()
+	}
+
+	/// Get the contents, in document order.
+	pub fn contents(&self) -> &[T] {
+		&self.contents
+	}
+
+	/// Iterates over the topology as balanced parentheses, `true` being an open one.
+	pub fn parens(&self) -> impl Iterator<Item = bool> + '_ {
+		(0..self.parens.len).map(|p| self.parens.get(p))
+	}
+
+	/// Get the packed parentheses: the one at position `p` is the bit `p % 64` of the word `p / 64`.
+	pub fn words(&self) -> &[u64] {
+		&self.parens.words
+	}
+
+	/// Get the position of the parenthesis opened by the node at `index`, if any.
+	fn open(&self, index: usize) -> Option<usize> {
+		match index < self.len() {
+			true => self.parens.select(index),
+			false => None
+		}
+	}
+
+	/// Get the content of the node at `index`, if any.
+	pub fn content(&self, index: usize) -> Option<&T> {
+		self.contents.get(index)
+	}
+
+	/// Get the parent of the node at `index`, if any.
+	pub fn parent(&self, index: usize) -> Option<usize> {
+		let open = self.parens.enclose(self.open(index)?)?;
+		Some(self.parens.rank(open))
+	}
+
+	/// Get the first child of the node at `index`, if any.
+	pub fn first_child(&self, index: usize) -> Option<usize> {
+		match self.parens.get(self.open(index)? + 1) {
+			true => Some(index + 1),
+			false => None
+		}
+	}
+
+	/// Get the next sibling of the node at `index`, if any.
+	pub fn next_sibling(&self, index: usize) -> Option<usize> {
+		let next = self.parens.close(self.open(index)?) + 1;
+
+		match next < self.parens.len && self.parens.get(next) {
+			true => Some(index + self.subtree_size(index)),
+			false => None
+		}
+	}
+
+	/// Iterates over the direct children of the node at `index`.
+	pub fn children(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+		std::iter::successors(self.first_child(index), move |child| self.next_sibling(*child))
+	}
+
+	/// Get the number of nodes in the subtree of the node at `index`, the node included.
+	/// Returns 0 if there is no node at `index`.
+	pub fn subtree_size(&self, index: usize) -> usize {
+		// the open parentheses before its closing one, minus the ones before its own.
+		self.open(index).map_or(0, |open| self.parens.rank(self.parens.close(open)) - index)
+	}
+
+	/// Get the depth of the node at `index`, 0 being a node without parent.
+	pub fn depth(&self, index: usize) -> usize {
+		// the open parentheses before it, minus the closed ones.
+		self.open(index).map_or(0, |open| 2 * index - open)
+	}
+}
+
+/// Appends the subtree of `root` ( `root` included ) to the contents and the parentheses.
+fn push_subtree<T: Debug + Clone>(root: &Node<T>, contents: &mut Vec<T>, parens: &mut Bits) {
+	// the nodes whose parenthesis is still open.
+	let mut open: Vec<Node<T>> = Vec::new();
+	let mut next = Some(root.clone());
+
+	while let Some(node) = next {
+		// closes every open node which isn't an ancestor of `node`.
+		while let Some(last) = open.last() {
+			if node.parent().is_some_and(|p| p.ptr_eq(last)) {
+				break;
+			}
+
+			open.pop();
+			parens.push(false);
+		}
+
+		contents.push(node.get().content.clone());
+		parens.push(true);
+		next = next_in_subtree(&node, root);
+		open.push(node);
+	}
+
+	for _ in open.iter() {
+		parens.push(false);
+	}
+}
+
+impl<T: Debug + Clone> Node<T> {
+	/// Copies the subtree of `&self` ( `&self` included ) into a `Succinct`, refer to it.
+	pub fn to_succinct(&self) -> Succinct<T> {
+		let mut contents = Vec::new();
+		let mut parens = Bits::new();
+
+		push_subtree(self, &mut contents, &mut parens);
+		Succinct {
+			contents,
+			parens: parens.index()
+		}
+	}
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Copies every root-level node of the linked list, along with its subtree, into a `Succinct`.
+	pub fn to_succinct(&self) -> Succinct<T> {
+		let mut contents = Vec::new();
+		let mut parens = Bits::new();
+
+		for root in std::iter::successors(self.first(), |node| node.next()) {
+			push_subtree(&root, &mut contents, &mut parens);
+		}
+
+		Succinct {
+			contents,
+			parens: parens.index()
+		}
+	}
+
+	/// Rebuilds a fully linked `List` from a `Succinct`, cloning its contents.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let node = node!("html", node!("head"), node!("body", node!("p")));
+	///		let list = List::from_succinct(&node.to_succinct());
+	///
+	///		let html = list.first().unwrap();
+	///		assert_eq!(html.get_last_child().unwrap().child().unwrap().to_content(), "p");
+	///		assert!(!html.ptr_eq(&node));
+	/// }
+	/// ```
+	pub fn from_succinct(tree: &Succinct<T>) -> Self {
+		let mut builder = TreeBuilder::new();
+		let mut contents = tree.contents.iter();
+
+		for paren in tree.parens() {
+			match paren {
+				true => {
+					if let Some(content) = contents.next() {
+						builder.start_node(content.clone());
+					}
+				},
+				false => {
+					builder.end_node();
+				}
+			}
+		}
+
+		builder.finish()
+	}
+}
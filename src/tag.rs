@@ -0,0 +1,95 @@
+use std::fmt::Debug;
+
+use crate::{
+	node::{
+		Node,
+		NodeCollection
+	},
+	list::List,
+	iter::next_in_subtree,
+};
+
+impl<T: Debug + Clone> Node<T> {
+	/// Attaches a string label to the node, returning `false` if it was already there.
+	/// Tags are a lightweight cross-cutting labeling mechanism ( e.g "dirty", "selected" ),
+	/// independent of the content and of `CompareNode` predicates.
+	///
+	/// Requires the `tags` feature.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2), node!(3)), node!(4));
+	///		let three = list.first().unwrap().get_last_child().unwrap();
+	///
+	///		assert!(three.add_tag("dirty"));
+	///		assert!(!three.add_tag("dirty"));
+	///		list.first().unwrap().next().unwrap().add_tag("dirty");
+	///
+	///		let dirty: Vec<i32> = list.collect_by_tag("dirty").into_iter().map(|n| n.to_content()).collect();
+	///		assert_eq!(dirty, vec![3, 4]);
+	///
+	///		assert!(three.remove_tag("dirty"));
+	///		assert!(!three.has_tag("dirty"));
+	/// }
+	/// ```
+	pub fn add_tag(&self, tag: &str) -> bool {
+		if self.has_tag(tag) {
+			return false;
+		}
+
+		self.get_mut().tags.push(tag.to_string());
+		true
+	}
+
+	/// Removes a label from the node, returning `false` if it wasn't there.
+	pub fn remove_tag(&self, tag: &str) -> bool {
+		let mut borrow = self.get_mut();
+
+		match borrow.tags.iter().position(|t| t == tag) {
+			Some(index) => {
+				borrow.tags.remove(index);
+				true
+			},
+			None => false
+		}
+	}
+
+	/// Returns `true` if the node carries the label.
+	pub fn has_tag(&self, tag: &str) -> bool {
+		self.get().tags.iter().any(|t| t == tag)
+	}
+
+	/// Get a copy of the labels attached to the node, in insertion order.
+	pub fn tags(&self) -> Vec<String> {
+		self.get().tags.clone()
+	}
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Collects every node of the linked list carrying the label, in document order.
+	pub fn collect_by_tag(&self, tag: &str) -> NodeCollection<T> {
+		let mut nodes = Vec::new();
+		let mut root = self.first();
+
+		while let Some(r) = root {
+			let mut next = Some(r.clone());
+
+			while let Some(node) = next {
+				next = next_in_subtree(&node, &r);
+
+				if node.has_tag(tag) {
+					nodes.push(node);
+				}
+			}
+
+			root = r.next();
+		}
+
+		NodeCollection::from_vec(nodes)
+	}
+}
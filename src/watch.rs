@@ -0,0 +1,65 @@
+use std::{
+	fmt::Debug,
+	sync::mpsc::{
+		self,
+		Receiver
+	},
+};
+
+use crate::{
+	list::List,
+	path::NodePath,
+};
+
+/// A structural change of a linked list, sent to the receivers returned by `List::watch`.
+/// Nodes are addressed by their `NodePath` at the time of the change, and no content is carried,
+/// so the events can be sent to other threads whatever the content type.
+///
+/// Moving nodes around sends a `Removed` event followed by an `Inserted` one.
+/// Changes to the contents are not structural, and aren't sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeEvent {
+	/// `count` siblings got inserted, along with their subtrees, the first one ending up at `path`.
+	Inserted { path: NodePath, count: usize },
+	/// `count` siblings got removed, along with their subtrees, starting from the one at `path`.
+	Removed { path: NodePath, count: usize }
+}
+
+impl<T: Debug + Clone> List<T> {
+	/// Get a channel notified of every structural change performed on the linked list from now on,
+	/// through the same mutation hooks as `List::start_recording`. The `Receiver` can be moved
+	/// to another thread, e.g a GUI thread refreshing its view of the model.
+	///
+	/// The linked list stops notifying a receiver once it gets dropped.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use hedel_rs::prelude::*;
+	/// use hedel_rs::*;
+	/// use hedel_rs::watch::TreeEvent;
+	/// use hedel_rs::path::NodePath;
+	///
+	/// fn main() {
+	///		let list = list!(node!(1, node!(2)), node!(3));
+	///		let events = list.watch();
+	///
+	///		let one = list.first().unwrap();
+	///		one.append_child(node!(4, node!(5)));
+	///		one.child().unwrap().detach();
+	///
+	///		let events: Vec<TreeEvent> = events.try_iter().collect();
+	///
+	///		assert_eq!(events, vec![
+	///			TreeEvent::Inserted { path: NodePath::new(vec![0, 1]), count: 1 },
+	///			TreeEvent::Removed { path: NodePath::new(vec![0, 0]), count: 1 }
+	///		]);
+	/// }
+	/// ```
+	pub fn watch(&self) -> Receiver<TreeEvent> {
+		let (sender, receiver) = mpsc::channel();
+		self.observe();
+		self.watchers.get_mut().push(sender);
+		receiver
+	}
+}